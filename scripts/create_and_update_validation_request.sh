@@ -2,7 +2,7 @@
 
 ### 1. Create a valid request for validation
 provenanced tx wasm execute "$VO_CONTRACT" \
-    '{ "request_validation": { "request": { "id": "12345", "scopes": ["scope1qqqtl0d4s2y59t5gwhj0mvsmwgxs20h2jc"], "quote": [] }}}' \
+    '{ "request_validation": { "request": { "id": "12345", "validation_type": "lauramachelocfull", "scopes": ["scope1qqqtl0d4s2y59t5gwhj0mvsmwgxs20h2jc"], "quote": [ { "amount": "3000", "denom": "nhash" } ] }}}' \
     --amount 3000nhash \
     --fees 382000000nhash \
     --from loan-originator \
@@ -25,7 +25,7 @@ provenanced query wasm contract-state smart "$VO_CONTRACT" "{\"query_validation_
 ### 4. Update the request we just made
 provenanced tx wasm execute "$VO_CONTRACT" \
     '{ "update_validation_request": { "request": { "current_id": "12345", "new_id": "54321", "new_quote": [ { "amount": "200000000", "denom": "nhash" } ] }}}' \
-    --amount 3000nhash \
+    --amount 199997000nhash \
     --fees 382000000nhash \
     --from loan-originator \
     --keyring-backend test \
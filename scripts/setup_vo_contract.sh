@@ -122,7 +122,7 @@ VO_CODE_ID=$(echo "$WASM_STORE" | jq -r '.logs[] | select(.msg_index == 0) | .ev
 
 ### Instantiate the contract
 provenanced tx wasm instantiate "$VO_CODE_ID" \
-    '{ "contract_name": "Validation Oracle Demo", "bind_name": "vo.sc.pb", "create_request_nhash_fee": "3000" }' \
+    '{ "contract_name": "Validation Oracle Demo", "bind_name": "vo.sc.pb", "fee_schedule": [{ "operation": "create_request", "amount": "3000", "denom": "nhash" }] }' \
     --admin "$ADMIN_ACCOUNT" \
     --label validation-oracle-demo \
     --from marketplace-admin \
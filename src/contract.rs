@@ -5,20 +5,35 @@ use crate::execute::validation_definition::{
     update_existing_validation_definition,
 };
 use crate::execute::validation_request::{
-    create_request_for_validation, delete_request_for_validation, update_request_for_validation,
+    accept_request_for_validation, cancel_request_for_validation, create_request_for_validation,
+    delete_request_for_validation, expire_requests_for_validation, release_request_for_validation,
+    update_request_for_validation,
+};
+use crate::execute::validation_result::{
+    dispute_validation_result, resolve_dispute, settle_validation_request,
+    submit_validation_results,
 };
 use crate::execute::validator_configuration::{
-    create_new_validator_configuration, update_existing_validator_configuration,
+    create_new_validator_configuration, delete_validator_configuration,
+    update_existing_validator_configuration,
 };
 use crate::instantiate::instantiate_contract;
 use crate::migrate::migrate_contract;
-use crate::query::contract_info::query_contract_info;
+use crate::query::contract_info::{query_contract_info, query_fee_schedule};
 use crate::query::entity::query_entity_by_address;
+use crate::query::migration::query_migration_dry_run;
 use crate::query::request::{
-    query_request_by_id, query_request_by_owner, query_request_by_validator,
+    query_open_requests, query_outstanding_scopes, query_request_by_id, query_request_by_owner,
+    query_request_by_validation_type, query_request_by_validator, query_requests_by_status,
+};
+use crate::query::validation_definition::{query_definition_by_type, query_definitions};
+use crate::query::validation_result::{query_results_by_request_id, query_results_by_validator};
+use crate::query::validator_configuration::{
+    query_validator_configuration, query_validator_configurations_by_type,
+    query_validator_configurations_by_validator,
 };
-use crate::query::validation_definition::query_definition_by_type;
 use crate::types::core::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::types::request::settings_update::SettingsUpdate;
 use crate::util::aliases::{DepsC, DepsMutC, EntryPointResponse, QueryResult};
 
 use cosmwasm_std::{entry_point, Env, MessageInfo};
@@ -29,13 +44,13 @@ use cosmwasm_std::{entry_point, Env, MessageInfo};
 /// # Parameters
 ///
 /// * `deps` A mutable dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like the contract's internal storage and a querier to retrieve blockchain objects.
+///   resources like the contract's internal storage and a querier to retrieve blockchain objects.
 /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.
+///   details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` A custom instantiation message defined by this contract for creating the initial
-/// configuration used by the contract.
+///   configuration used by the contract.
 #[entry_point]
 pub fn instantiate(
     deps: DepsMutC,
@@ -54,14 +69,14 @@ pub fn instantiate(
 /// # Parameters
 ///
 /// * `deps` A mutable dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like the contract's internal storage and a querier to retrieve blockchain objects.
+///   resources like the contract's internal storage and a querier to retrieve blockchain objects.
 /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.
+///   details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` A custom execution message enum defined by this contract to allow multiple different
-/// processes to be defined for the singular execution route entry point allowed by the
-/// cosmwasm framework.
+///   processes to be defined for the singular execution route entry point allowed by the
+///   cosmwasm framework.
 #[entry_point]
 pub fn execute(deps: DepsMutC, env: Env, info: MessageInfo, msg: ExecuteMsg) -> EntryPointResponse {
     match msg {
@@ -82,16 +97,45 @@ pub fn execute(deps: DepsMutC, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
         ExecuteMsg::UpdateValidatorConfiguration { request } => {
             update_existing_validator_configuration(deps, env, info, request)
         }
+        ExecuteMsg::DeleteValidatorConfiguration {
+            validator,
+            validation_type,
+            force,
+        } => delete_validator_configuration(deps, env, info, validator, validation_type, force),
         ExecuteMsg::RequestValidation { request } => {
             create_request_for_validation(deps, env, info, request)
         }
         ExecuteMsg::UpdateValidationRequest { request } => {
             update_request_for_validation(deps, env, info, request)
         }
-        ExecuteMsg::DeleteValidationRequest { id } => {
-            delete_request_for_validation(deps, env, info, id)
+        ExecuteMsg::DeleteValidationRequest { id, force } => {
+            delete_request_for_validation(deps, env, info, id, force)
+        }
+        ExecuteMsg::CancelValidationRequest { id } => {
+            cancel_request_for_validation(deps, env, info, id)
+        }
+        ExecuteMsg::AcceptValidationRequest { id } => {
+            accept_request_for_validation(deps, env, info, id)
+        }
+        ExecuteMsg::ReleaseValidationRequest { id, reason } => {
+            release_request_for_validation(deps, env, info, id, reason)
+        }
+        ExecuteMsg::ExpireValidationRequests { limit } => {
+            expire_requests_for_validation(deps, env, info, limit)
+        }
+        ExecuteMsg::SubmitValidationResults { results } => {
+            submit_validation_results(deps, env, info, results)
         }
-        ExecuteMsg::UpdateSettings { update } => update_settings(deps, info, update),
+        ExecuteMsg::DisputeValidationResult { id, reason } => {
+            dispute_validation_result(deps, env, info, id, reason)
+        }
+        ExecuteMsg::ResolveDispute { id, resolution } => {
+            resolve_dispute(deps, env, info, id, resolution)
+        }
+        ExecuteMsg::SettleValidationRequest { id } => {
+            settle_validation_request(deps, env, info, id)
+        }
+        ExecuteMsg::UpdateSettings { update } => update_settings(deps, env, info, update),
     }
 }
 
@@ -102,12 +146,12 @@ pub fn execute(deps: DepsMutC, env: Env, info: MessageInfo, msg: ExecuteMsg) ->
 /// # Parameters
 ///
 /// * `deps` An immutable dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like the contract's internal storage and a querier to retrieve blockchain objects.
+///   resources like the contract's internal storage and a querier to retrieve blockchain objects.
 /// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successful query entrypoint.
+///   details, as well as blockchain information at the time of the transaction.  Unused by this
+///   function, but required by cosmwasm for successful query entrypoint.
 /// * `msg` A custom query message enum defined by this contract to allow multiple different results
-/// to be determined for this route.
+///   to be determined for this route.
 #[entry_point]
 pub fn query(deps: DepsC, _env: Env, msg: QueryMsg) -> QueryResult {
     match msg {
@@ -117,14 +161,90 @@ pub fn query(deps: DepsC, _env: Env, msg: QueryMsg) -> QueryResult {
         QueryMsg::QueryValidationDefinitionByType { r#type } => {
             query_definition_by_type(deps.storage, r#type)
         }
+        QueryMsg::QueryValidationDefinitions {
+            enabled,
+            display_name_prefix,
+            start_after,
+            limit,
+        } => query_definitions(
+            deps.storage,
+            enabled,
+            display_name_prefix,
+            start_after,
+            limit,
+        ),
         QueryMsg::QueryValidationRequestById { id } => query_request_by_id(deps.storage, id),
-        QueryMsg::QueryValidationRequestByOwner { owner } => {
-            query_request_by_owner(deps.storage, owner)
+        QueryMsg::QueryOutstandingScopes { id, validator } => {
+            query_outstanding_scopes(deps.storage, id, validator)
         }
-        QueryMsg::QueryValidationRequestByValidator { validator } => {
-            query_request_by_validator(deps.storage, validator)
+        QueryMsg::QueryValidationRequestByOwner {
+            owner,
+            start_after,
+            limit,
+        } => query_request_by_owner(deps.storage, owner, start_after, limit),
+        QueryMsg::QueryValidationRequestByType {
+            r#type,
+            start_after,
+            limit,
+        } => query_request_by_validation_type(deps.storage, r#type, start_after, limit),
+        QueryMsg::QueryValidationRequestByValidator {
+            validator,
+            start_after,
+            limit,
+        } => query_request_by_validator(deps.storage, validator, start_after, limit),
+        QueryMsg::QueryValidationRequestsByStatus {
+            status,
+            validation_type,
+            owner,
+            start_after,
+            limit,
+        } => query_requests_by_status(
+            deps.storage,
+            status,
+            validation_type,
+            owner,
+            start_after,
+            limit,
+        ),
+        QueryMsg::QueryOpenValidationRequests { start_after, limit } => {
+            query_open_requests(deps.storage, start_after, limit)
         }
+        QueryMsg::QueryValidationResultsByRequestId {
+            id,
+            start_after,
+            limit,
+        } => query_results_by_request_id(deps.storage, id, start_after, limit),
+        QueryMsg::QueryValidationResultsByValidator {
+            validator,
+            start_after,
+            limit,
+        } => query_results_by_validator(deps.storage, validator, start_after, limit),
+        QueryMsg::QueryValidatorConfiguration {
+            validator,
+            validation_type,
+        } => query_validator_configuration(deps.storage, validator, validation_type),
+        QueryMsg::QueryValidatorConfigurationsByValidator {
+            validator,
+            start_after,
+            limit,
+        } => {
+            query_validator_configurations_by_validator(deps.storage, validator, start_after, limit)
+        }
+        QueryMsg::QueryValidatorConfigurationsByType {
+            validation_type,
+            cost_denom,
+            start_after,
+            limit,
+        } => query_validator_configurations_by_type(
+            deps.storage,
+            validation_type,
+            cost_denom,
+            start_after,
+            limit,
+        ),
         QueryMsg::QueryContractInfo {} => query_contract_info(deps.storage),
+        QueryMsg::QueryFeeSchedule {} => query_fee_schedule(deps.storage),
+        QueryMsg::QueryMigrationDryRun {} => query_migration_dry_run(deps.storage),
     }
 }
 
@@ -135,15 +255,28 @@ pub fn query(deps: DepsC, _env: Env, msg: QueryMsg) -> QueryResult {
 /// # Parameters
 ///
 /// * `deps` A mutable dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like the contract's internal storage and a querier to retrieve blockchain objects.
-/// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successful migration entrypoint.
+///   resources like the contract's internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+///   details, as well as blockchain information at the time of the transaction.
 /// * `msg` A custom migrate message enum defined by this contract to allow multiple different
-/// results of invoking the migrate endpoint.
+///   results of invoking the migrate endpoint.
 #[entry_point]
-pub fn migrate(deps: DepsMutC, _env: Env, msg: MigrateMsg) -> EntryPointResponse {
+pub fn migrate(deps: DepsMutC, env: Env, msg: MigrateMsg) -> EntryPointResponse {
     match msg {
-        MigrateMsg::ContractUpgrade {} => migrate_contract(deps),
+        MigrateMsg::ContractUpgrade {
+            new_admin_address,
+            new_fee_schedule,
+            new_contract_name,
+            new_bind_name,
+        } => migrate_contract(
+            deps,
+            env,
+            SettingsUpdate {
+                new_admin_address,
+                new_fee_schedule,
+                new_contract_name,
+                new_bind_name,
+            },
+        ),
     }
 }
@@ -2,4 +2,5 @@ pub mod entity;
 pub mod update_settings;
 pub mod validation_definition;
 pub mod validation_request;
+pub mod validation_result;
 pub mod validator_configuration;
@@ -1,61 +1,229 @@
 use crate::storage::contract_info::{get_contract_info, set_contract_info};
 use crate::types::core::error::ContractError;
 use crate::types::request::settings_update::SettingsUpdate;
-use crate::util::aliases::DepsMutC;
+use crate::util::aliases::{DepsMutC, EntryPointResponse};
 use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::helpers::{
+    apply_settings_update, check_admin_only, check_funds_are_empty, get_contract_state_update,
+    rebind_contract_names,
+};
 
-use cosmwasm_std::{MessageInfo, Response};
-use provwasm_std::ProvenanceMsg;
+use cosmwasm_std::{Env, MessageInfo, Response};
 use result_extensions::ResultExtensions;
 
+/// Changes the contract's settings, moving the contract's name binding and those of its
+/// [validation definitions](crate::types::validation_definition::ValidationDefinition) if the
+/// bind name changes.
+///
+/// # Parameters
+///
+/// * `deps` A mutable dependencies object provided by the cosmwasm framework.
+/// * `env` An environment object provided by the cosmwasm framework.
+/// * `info` A message information object provided by the cosmwasm framework.
+/// * `update` The changes to apply to the contract's settings.
 pub fn update_settings(
     deps: DepsMutC,
+    env: Env,
     info: MessageInfo,
     update: SettingsUpdate,
-) -> Result<Response<ProvenanceMsg>, ContractError> {
-    validate_settings_update(&update)?;
-    let mut contract_info = get_contract_info(deps.storage)?;
-    if info.sender != contract_info.admin {
-        return ContractError::Unauthorized {
-            reason: "must be the contract admin".to_string(),
+) -> EntryPointResponse {
+    // Validate the request
+    check_admin_only(&deps.as_ref(), &info)?;
+    check_funds_are_empty(&info)?;
+    let old_contract_info = get_contract_info(deps.storage)?;
+    let new_contract_info = apply_settings_update(deps.api, &old_contract_info, &update)?;
+    let contract_state_update = get_contract_state_update(&old_contract_info, &new_contract_info);
+    if !contract_state_update.has_metadata() {
+        return ContractError::InvalidRequest {
+            message: "No actual changes to the contract settings were specified".to_string(),
         }
         .to_err();
     }
-    if !info.funds.is_empty() {
-        return ContractError::InvalidFunds {
-            message: "funds cannot be provided during a settings update".to_string(),
-        }
-        .to_err();
-    }
-    let mut attributes = vec![];
-    if let Some(ref new_admin) = &update.new_admin_address {
-        contract_info.admin = deps.api.addr_validate(new_admin)?;
-        attributes.push(("new_admin_address".to_string(), new_admin.to_string()));
-    }
+    // Move the contract's name bindings if its bind name changed
+    let messages = if new_contract_info.bind_name != old_contract_info.bind_name {
+        rebind_contract_names(
+            deps.storage,
+            &env.contract.address,
+            &old_contract_info.bind_name,
+            &new_contract_info.bind_name,
+        )?
+    } else {
+        vec![]
+    };
     // Save changes to the contract information
-    set_contract_info(deps.storage, &contract_info)?;
+    set_contract_info(deps.storage, &new_contract_info)?;
+    // Construct the response
     Response::new()
-        .add_attributes(EventAttributes::new(EventType::UpdateSettings))
-        .add_attributes(attributes)
+        .add_messages(messages)
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateSettings)
+                .set_contract_info(&new_contract_info)
+                .set_additional_metadata(&contract_state_update),
+        )
         .to_ok()
 }
 
-fn validate_settings_update(msg: &SettingsUpdate) -> Result<(), ContractError> {
-    let mut errors = vec![];
-    if let Some(ref new_admin_address) = msg.new_admin_address {
-        if new_admin_address.is_empty() {
-            errors.push("new_admin_address was empty".to_string());
+#[cfg(test)]
+mod tests {
+    use super::update_settings;
+    use crate::{
+        storage::{
+            contract_info::get_contract_info,
+            validation_definition::{get_validation_definition, update_validation_definition},
+        },
+        test::helpers::{
+            set_up_contract_info, set_up_validation_definition, single_attribute_for_key,
+            TEST_ADMIN, TEST_BIND_NAME,
+        },
+        types::{
+            core::error::ContractError,
+            fee::{ContractFee, FeeDenom, FeeOperation},
+            request::settings_update::SettingsUpdate,
+        },
+        util::{
+            constants::ADDITIONAL_METADATA_KEY,
+            functions::generate_validation_definition_attribute_name,
+        },
+    };
+
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{Addr, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{bind_name, unbind_name, NameBinding};
+
+    fn empty_update() -> SettingsUpdate {
+        SettingsUpdate {
+            new_admin_address: None,
+            new_fee_schedule: None,
+            new_contract_name: None,
+            new_bind_name: None,
         }
     }
-    if !errors.is_empty() {
-        ContractError::InvalidRequest {
-            message: errors.join(", "),
-        }
-        .to_err()
-    } else {
-        Ok(())
+
+    #[test]
+    fn update_settings_requires_admin_and_changes() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let update = SettingsUpdate {
+            new_contract_name: Some("New Oracle".to_string()),
+            ..empty_update()
+        };
+        assert!(matches!(
+            update_settings(
+                deps.as_mut(),
+                mock_env(),
+                mock_info("notadmin", &[]),
+                update
+            ),
+            Err(ContractError::Unauthorized { .. })
+        ));
+        assert!(matches!(
+            update_settings(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_ADMIN, &[]),
+                empty_update()
+            ),
+            Err(ContractError::InvalidRequest { .. })
+        ));
+        let blank_bind_name = SettingsUpdate {
+            new_bind_name: Some(" ".to_string()),
+            ..empty_update()
+        };
+        assert!(matches!(
+            update_settings(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_ADMIN, &[]),
+                blank_bind_name
+            ),
+            Err(ContractError::InvalidRequest { .. })
+        ));
     }
-}
 
-#[cfg(test)]
-mod tests {}
+    #[test]
+    fn update_settings_rebinds_names() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let mut bound_definition = set_up_validation_definition(&mut deps, "bound", true);
+        let old_definition_name =
+            generate_validation_definition_attribute_name("bound", TEST_BIND_NAME);
+        bound_definition.bound_name = Some(old_definition_name.clone());
+        update_validation_definition(deps.as_mut().storage, &bound_definition).unwrap();
+        set_up_validation_definition(&mut deps, "unbound", true);
+
+        let response = update_settings(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(TEST_ADMIN, &[]),
+            SettingsUpdate {
+                new_admin_address: Some("newadmin".to_string()),
+                new_fee_schedule: Some(vec![ContractFee {
+                    operation: FeeOperation::AcceptRequest,
+                    amount: Uint128::new(123),
+                    denom: FeeDenom::Usd,
+                    recipient: Some(Addr::unchecked("feerecipient")),
+                }]),
+                new_contract_name: Some("New Oracle".to_string()),
+                new_bind_name: Some("new-oracle.pb".to_string()),
+            },
+        )
+        .expect("updating the contract settings should succeed");
+
+        let contract_address = mock_env().contract.address;
+        let new_definition_name =
+            generate_validation_definition_attribute_name("bound", "new-oracle.pb");
+        assert_eq!(
+            vec![
+                unbind_name(&old_definition_name).unwrap(),
+                unbind_name(TEST_BIND_NAME).unwrap(),
+                bind_name(
+                    "new-oracle.pb",
+                    contract_address.clone(),
+                    NameBinding::Restricted
+                )
+                .unwrap(),
+                bind_name(
+                    &new_definition_name,
+                    contract_address,
+                    NameBinding::Restricted
+                )
+                .unwrap(),
+            ],
+            response
+                .messages
+                .iter()
+                .map(|msg| msg.msg.clone())
+                .collect::<Vec<_>>()
+        );
+        let additional_metadata = single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY);
+        assert!(additional_metadata.contains("[new_admin=newadmin]"));
+        assert!(additional_metadata.contains("[old_fee_schedule=none]"));
+        assert!(additional_metadata
+            .contains("[new_fee_schedule=accept_request: 123usd to feerecipient]"));
+        assert!(additional_metadata.contains("[new_contract_name=New Oracle]"));
+        assert!(additional_metadata.contains("[old_bind_name=validation-oracle.pb]"));
+        let contract_info = get_contract_info(&deps.storage).unwrap();
+        assert_eq!("newadmin", contract_info.admin.as_str());
+        assert_eq!(
+            Some(Uint128::new(123)),
+            contract_info
+                .maybe_get_fee(FeeOperation::AcceptRequest)
+                .map(|fee| fee.amount)
+        );
+        assert_eq!("New Oracle", contract_info.contract_name);
+        assert_eq!("new-oracle.pb", contract_info.bind_name);
+        assert_eq!(
+            Some(new_definition_name.as_str()),
+            get_validation_definition(&deps.storage, "bound")
+                .unwrap()
+                .maybe_get_bound_name()
+        );
+        assert_eq!(
+            None,
+            get_validation_definition(&deps.storage, "unbound")
+                .unwrap()
+                .bound_name
+        );
+    }
+}
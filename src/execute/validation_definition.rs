@@ -8,20 +8,23 @@ use crate::{
     },
     types::{
         core::error::ContractError,
+        fee::FeeOperation,
         request::validation_definition::{
             ValidationDefinitionCreationRequest, ValidationDefinitionUpdateRequest,
         },
+        validation_definition::ValidationDefinition,
     },
     util::{
         aliases::{ContractResult, DepsC, DepsMutC, EntryPointResponse},
         event_attributes::{EventAttributes, EventType},
+        fees::{add_contract_fee_msg, generate_contract_fee_msg},
         functions::generate_validation_definition_attribute_name,
         helpers::{check_admin_only, check_funds_are_empty, get_validation_definition_update},
     },
 };
 
-use cosmwasm_std::{to_binary, Env, MessageInfo, Response};
-use provwasm_std::{bind_name, NameBinding};
+use cosmwasm_std::{to_binary, Decimal, Env, MessageInfo, Response};
+use provwasm_std::{bind_name, unbind_name, NameBinding};
 use result_extensions::ResultExtensions;
 
 pub fn create_new_validation_definition(
@@ -32,36 +35,45 @@ pub fn create_new_validation_definition(
 ) -> EntryPointResponse {
     // Validate the request
     validate_request(&deps.as_ref(), &info, &request)?;
-    // Store the definition
-    let stored_definition = request.clone().into();
-    insert_validation_definition(deps.storage, &stored_definition)?;
     // Bind the validation type as a name to the contract address, unless the request explicitly specifies not to
+    let mut stored_definition: ValidationDefinition = request.clone().into();
     let mut messages = vec![];
     if request.bind_name.unwrap_or(true) {
+        let name = generate_validation_definition_attribute_name(
+            &request.validation_type,
+            get_contract_info(deps.storage)?.bind_name,
+        );
         messages.push(bind_name(
-            // TODO: Store bind name! Even better, create its own storage map for durability against migrations?
-            generate_validation_definition_attribute_name(
-                &request.validation_type,
-                get_contract_info(deps.storage)?.bind_name,
-            ),
-            env.contract.address,
+            &name,
+            env.contract.address.clone(),
             NameBinding::Restricted,
         )?);
+        stored_definition.bound_name = Some(name);
     }
+    // Store the definition
+    insert_validation_definition(deps.storage, &stored_definition)?;
+    let fee_msg = generate_contract_fee_msg(
+        FeeOperation::CreateDefinition,
+        &deps.as_ref(),
+        env.contract.address,
+    )?;
     // Construct the response
-    Response::new()
-        .add_messages(messages)
-        .add_attributes(
-            EventAttributes::new(EventType::AddValidationDefinition)
-                .set_validation_type(&request.validation_type),
-        )
-        .set_data(to_binary(&stored_definition)?) // TODO: Examine what this looks like
-        .to_ok()
+    add_contract_fee_msg(
+        Response::new()
+            .add_messages(messages)
+            .add_attributes(
+                EventAttributes::new(EventType::AddValidationDefinition)
+                    .set_validation_type(&request.validation_type),
+            )
+            .set_data(to_binary(&stored_definition)?), // TODO: Examine what this looks like
+        FeeOperation::CreateDefinition,
+        fee_msg,
+    )
 }
 
 pub fn update_existing_validation_definition(
     deps: DepsMutC,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     request: ValidationDefinitionUpdateRequest,
 ) -> EntryPointResponse {
@@ -69,6 +81,7 @@ pub fn update_existing_validation_definition(
     // Validate the request
     check_admin_only(&deps.as_ref(), &info)?;
     check_funds_are_empty(&info)?;
+    validate_cancellation_fraction(request.new_cancellation_fraction)?;
     let key_description = ValidationDefinitionUpdateRequest::get_storage_key_description();
     let old_definition = get_validation_definition(deps.storage, request.old_storage_key())
         .map_err(|err| ContractError::InvalidRequest {
@@ -81,7 +94,17 @@ pub fn update_existing_validation_definition(
         })?;
     let mut errors = vec![];
     let maybe_new_storage_key = request.maybe_get_new_storage_key();
-    let new_definition = request.clone().into();
+    let is_rename = maybe_new_storage_key.is_some();
+    let mut new_definition: ValidationDefinition = request.clone().into();
+    new_definition.bound_name = old_definition.bound_name.clone();
+    // Keep the settings which the request does not change
+    new_definition.cancellation_fraction = request
+        .new_cancellation_fraction
+        .or(old_definition.cancellation_fraction);
+    new_definition.dispute_period = request
+        .new_dispute_period
+        .clone()
+        .or_else(|| old_definition.dispute_period.clone());
     let definition_update_metadata =
         get_validation_definition_update(&old_definition, &new_definition);
     match maybe_new_storage_key {
@@ -115,10 +138,31 @@ pub fn update_existing_validation_definition(
         }
         .to_err();
     }
+    // Move the name bound for the validation type to the new validation type, if it was renamed
+    let mut messages = vec![];
+    if let Some(old_name) = old_definition.maybe_get_bound_name().filter(|_| is_rename) {
+        let new_name = generate_validation_definition_attribute_name(
+            &new_definition.validation_type,
+            get_contract_info(deps.storage)?.bind_name,
+        );
+        messages.push(unbind_name(old_name)?);
+        messages.push(bind_name(
+            &new_name,
+            env.contract.address,
+            NameBinding::Restricted,
+        )?);
+        new_definition.bound_name = Some(new_name);
+    }
     // Update the definition
-    store_validation_definition(deps.storage, &new_definition, Some(&old_definition))?;
+    if is_rename {
+        delete_validation_definition_by_key(deps.storage, request.old_storage_key())?;
+        insert_validation_definition(deps.storage, &new_definition)?;
+    } else {
+        store_validation_definition(deps.storage, &new_definition, Some(&old_definition))?;
+    }
     // Construct the response
     Response::new()
+        .add_messages(messages)
         .add_attributes(
             EventAttributes::new(EventType::UpdateValidationDefinition)
                 .set_validation_type(
@@ -153,10 +197,258 @@ pub fn delete_validation_definition(
 fn validate_request(
     deps: &DepsC,
     info: &MessageInfo,
-    _request: &ValidationDefinitionCreationRequest,
+    request: &ValidationDefinitionCreationRequest,
 ) -> ContractResult<()> {
     check_admin_only(deps, info)?;
     check_funds_are_empty(info)?;
     // TODO: Add regex check for validation_type being a valid name if bind_name isn't false, to preempt the provenance error with a more descriptive one
-    Ok(())
+    validate_cancellation_fraction(request.cancellation_fraction)
+}
+
+/// Checks that a cancellation fraction, if given, is no greater than one.
+///
+/// # Parameters
+///
+/// * `cancellation_fraction` The fraction of a claimed request's quote paid to its validator upon cancellation.
+fn validate_cancellation_fraction(cancellation_fraction: Option<Decimal>) -> ContractResult<()> {
+    match cancellation_fraction {
+        Some(fraction) if fraction > Decimal::one() => ContractError::InvalidRequest {
+            message: format!(
+                "cancellation fraction [{}] cannot be greater than one",
+                fraction
+            ),
+        }
+        .to_err(),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{create_new_validation_definition, update_existing_validation_definition};
+    use crate::{
+        storage::validation_definition::{
+            get_validation_definition, may_get_validation_definition,
+        },
+        test::helpers::{set_up_contract_info, TEST_ADMIN, TEST_BIND_NAME},
+        types::{
+            expiration::Duration,
+            request::validation_definition::{
+                ValidationDefinitionCreationRequest, ValidationDefinitionUpdateRequest,
+            },
+        },
+    };
+
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::Decimal;
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{bind_name, unbind_name, NameBinding};
+
+    fn creation_request(validation_type: &str, bind: bool) -> ValidationDefinitionCreationRequest {
+        ValidationDefinitionCreationRequest {
+            validation_type: validation_type.to_string(),
+            display_name: None,
+            enabled: None,
+            bind_name: Some(bind),
+            cancellation_fraction: None,
+            dispute_period: None,
+        }
+    }
+
+    fn update_request(current_validation_type: &str) -> ValidationDefinitionUpdateRequest {
+        ValidationDefinitionUpdateRequest {
+            current_validation_type: current_validation_type.to_string(),
+            new_validation_type: None,
+            new_display_name: None,
+            enabled: None,
+            new_cancellation_fraction: None,
+            new_dispute_period: None,
+        }
+    }
+
+    #[test]
+    fn update_keeps_unchanged_cancellation_fraction() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let info = mock_info(TEST_ADMIN, &[]);
+        create_new_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ValidationDefinitionCreationRequest {
+                cancellation_fraction: Some(Decimal::percent(25)),
+                ..creation_request("test-type", false)
+            },
+        )
+        .expect("creating a validation definition should succeed");
+
+        update_existing_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ValidationDefinitionUpdateRequest {
+                new_display_name: Some("Test Type".to_string()),
+                ..update_request("test-type")
+            },
+        )
+        .expect("updating the display name of a validation definition should succeed");
+        assert_eq!(
+            Decimal::percent(25),
+            get_validation_definition(&deps.storage, "test-type")
+                .unwrap()
+                .get_cancellation_fraction()
+        );
+
+        update_existing_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ValidationDefinitionUpdateRequest {
+                new_display_name: Some("Test Type".to_string()),
+                new_cancellation_fraction: Some(Decimal::percent(50)),
+                ..update_request("test-type")
+            },
+        )
+        .expect("updating the cancellation fraction of a validation definition should succeed");
+        assert_eq!(
+            Decimal::percent(50),
+            get_validation_definition(&deps.storage, "test-type")
+                .unwrap()
+                .get_cancellation_fraction()
+        );
+    }
+
+    #[test]
+    fn update_keeps_unchanged_dispute_period() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let info = mock_info(TEST_ADMIN, &[]);
+        create_new_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ValidationDefinitionCreationRequest {
+                dispute_period: Some(Duration::Height(100)),
+                ..creation_request("test-type", false)
+            },
+        )
+        .expect("creating a validation definition should succeed");
+
+        update_existing_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            ValidationDefinitionUpdateRequest {
+                new_display_name: Some("Test Type".to_string()),
+                ..update_request("test-type")
+            },
+        )
+        .expect("updating the display name of a validation definition should succeed");
+        assert_eq!(
+            Some(&Duration::Height(100)),
+            get_validation_definition(&deps.storage, "test-type")
+                .unwrap()
+                .maybe_get_dispute_period()
+        );
+
+        update_existing_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ValidationDefinitionUpdateRequest {
+                new_display_name: Some("Test Type".to_string()),
+                new_dispute_period: Some(Duration::Time(3600)),
+                ..update_request("test-type")
+            },
+        )
+        .expect("updating the dispute period of a validation definition should succeed");
+        assert_eq!(
+            Some(&Duration::Time(3600)),
+            get_validation_definition(&deps.storage, "test-type")
+                .unwrap()
+                .maybe_get_dispute_period()
+        );
+    }
+
+    #[test]
+    fn rename_bound_validation_definition() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let info = mock_info(TEST_ADMIN, &[]);
+        create_new_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            creation_request("old-type", true),
+        )
+        .expect("creating a bound validation definition should succeed");
+
+        let response = update_existing_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ValidationDefinitionUpdateRequest {
+                new_validation_type: Some("new-type".to_string()),
+                ..update_request("old-type")
+            },
+        )
+        .expect("renaming a bound validation definition should succeed");
+        assert_eq!(
+            vec![
+                unbind_name(format!("old-type.{}", TEST_BIND_NAME)).unwrap(),
+                bind_name(
+                    format!("new-type.{}", TEST_BIND_NAME),
+                    mock_env().contract.address,
+                    NameBinding::Restricted
+                )
+                .unwrap(),
+            ],
+            response
+                .messages
+                .iter()
+                .map(|msg| msg.msg.clone())
+                .collect::<Vec<_>>()
+        );
+        let new_name = format!("new-type.{}", TEST_BIND_NAME);
+        assert_eq!(
+            Some(new_name.as_str()),
+            get_validation_definition(&deps.storage, "new-type")
+                .unwrap()
+                .maybe_get_bound_name()
+        );
+        assert!(may_get_validation_definition(&deps.storage, "old-type").is_none());
+    }
+
+    #[test]
+    fn rename_unbound_validation_definition() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let info = mock_info(TEST_ADMIN, &[]);
+        create_new_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info.clone(),
+            creation_request("old-type", false),
+        )
+        .expect("creating an unbound validation definition should succeed");
+
+        let response = update_existing_validation_definition(
+            deps.as_mut(),
+            mock_env(),
+            info,
+            ValidationDefinitionUpdateRequest {
+                new_validation_type: Some("new-type".to_string()),
+                ..update_request("old-type")
+            },
+        )
+        .expect("renaming an unbound validation definition should succeed");
+        assert!(response.messages.is_empty());
+        assert_eq!(
+            None,
+            get_validation_definition(&deps.storage, "new-type")
+                .unwrap()
+                .maybe_get_bound_name()
+        );
+        assert!(may_get_validation_definition(&deps.storage, "old-type").is_none());
+    }
 }
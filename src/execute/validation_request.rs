@@ -1,20 +1,38 @@
 use crate::{
     storage::{
         contract_info::get_contract_info,
-        request::{delete_request_by_id, get_request, insert_request, store_request},
+        request::{
+            delete_request_by_id, get_expired_requests, get_request, insert_request, store_request,
+        },
+        validation_definition::may_get_validation_definition,
+        validation_result::{
+            delete_validation_result, delete_validation_results_by_request,
+            get_agreeing_validation_results,
+        },
+        validator_configuration::get_validator_configuration,
     },
     types::{
+        access_definition::AccessDefinitionType,
         core::error::ContractError,
+        fee::FeeOperation,
         request::validation_request::{
-            ValidationRequest, ValidationRequestType, ValidationRequestUpdate,
+            ValidationRequest, ValidationRequestClaim, ValidationRequestStatus,
+            ValidationRequestType, ValidationRequestUpdate,
         },
+        validation_definition::ValidationDefinition,
+        validator_configuration::ValidatorConfiguration,
     },
     util::{
         aliases::{DepsMutC, EntryPointResponse},
         create_request_utilities::{form_validation_request, ValidationRequestCreationResponse},
-        event_attributes::{EventAttributes, EventType},
-        fees::get_custom_fee_amount_display,
-        helpers::get_validation_request_update,
+        escrow::{
+            check_funds_match, display_coins, divide_coins, generate_payment_msg, multiply_coins,
+            subtract_coins,
+        },
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        fees::{add_contract_fee_msg, generate_contract_fee_msg},
+        helpers::{check_funds_are_empty, get_validation_request_update},
+        pagination::get_page_limit,
     },
 };
 
@@ -35,6 +53,8 @@ pub fn create_request_for_validation(
         }
         .to_err();
     }
+    // The quote is held in escrow by the contract until the request is fulfilled or removed
+    check_funds_match(&info, &request.quote, "a validation request")?;
     // TODO: Should we let validation requests (...I forgot the rest, leaving this here as a note in case I remember)
     // Form the request's messages
     let ValidationRequestCreationResponse {
@@ -45,19 +65,14 @@ pub fn create_request_for_validation(
     // Insert the request
     insert_request(deps.storage, &request_order)?;
     // Create and return a response
-    let mut response = Response::new()
+    let response = Response::new()
         .add_messages(messages)
-        .add_attributes(EventAttributes::new(EventType::AddValidationRequest))
+        .add_attributes(
+            EventAttributes::new(EventType::AddValidationRequest)
+                .set_validation_type(request_order.get_validation_type()),
+        )
         .set_data(to_binary(&request_order)?); // TODO: Add set_data calls to other entry point responses
-    if let Some(request_fee_msg) = request_fee_msg {
-        response = response
-            .add_attribute(
-                "request_creation_fee_charged",
-                get_custom_fee_amount_display(&request_fee_msg)?,
-            )
-            .add_message(request_fee_msg);
-    }
-    response.to_ok()
+    add_contract_fee_msg(response, FeeOperation::CreateRequest, request_fee_msg)
 }
 
 pub fn update_request_for_validation(
@@ -66,17 +81,8 @@ pub fn update_request_for_validation(
     info: MessageInfo,
     request: ValidationRequestUpdate,
 ) -> EntryPointResponse {
-    // TODO: Complete details
     // Validate the request
-    let _state = get_contract_info(deps.storage)?;
-    // TODO: Further validation that request actually changes something
-    // if info.sender != existing_request.owner && info.sender != state.admin {
-    //     return ContractError::Unauthorized {
-    //         reason: "Must be the contract admin to update a validation request owned by a different address"
-    //             .to_string(),
-    //     }
-    //     .to_err();
-    // }
+    let state = get_contract_info(deps.storage)?;
     let old_request = get_request(deps.storage, request.get_current_id()).map_err(|err| {
         ContractError::InvalidRequest {
             message: format!(
@@ -86,13 +92,68 @@ pub fn update_request_for_validation(
             ),
         }
     })?;
-    // TODO: Use to_owned over clone for this block? Use only accessors over direct?
-    let mut errors = vec![];
+    if info.sender != old_request.owner && info.sender != state.admin {
+        return ContractError::Unauthorized {
+            reason: "must be the contract admin to update a validation request owned by a different address"
+                .to_string(),
+        }
+        .to_err();
+    }
+    if old_request.status != ValidationRequestStatus::Requested {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot be updated because its status is [{}]",
+                old_request.id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    if !old_request.get_claims().is_empty() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot be updated because it has been accepted by a validator",
+                old_request.id
+            ),
+        }
+        .to_err();
+    }
     let maybe_new_storage_key = request.maybe_get_new_id();
+    if let Some(new_storage_key) = maybe_new_storage_key {
+        if request.get_current_id() == new_storage_key {
+            return ContractError::InvalidRequest {
+                message: "cannot specify a new ID which is the same as the old ID".to_string(),
+            }
+            .to_err();
+        } else if get_request(deps.storage, new_storage_key).is_ok() {
+            return ContractError::ExistingId {
+                id: new_storage_key.to_string(),
+                id_type: "request".to_string(),
+            }
+            .to_err();
+        }
+    }
+    // Any increase to the quote must be added to escrow, and any decrease is refunded to the owner
+    let (quote_increase, quote_decrease) = match request.maybe_get_new_quote() {
+        Some(new_quote) => (
+            subtract_coins(new_quote, old_request.get_quote()),
+            subtract_coins(old_request.get_quote(), new_quote),
+        ),
+        None => (vec![], vec![]),
+    };
+    check_funds_match(
+        &info,
+        &quote_increase,
+        "an update to the quote of a validation request",
+    )?;
+    // Form the updated request
     let creation_request = ValidationRequest {
         id: maybe_new_storage_key
             .unwrap_or_else(|| request.get_current_id())
             .to_string(),
+        validation_type: request
+            .maybe_get_new_validation_type()
+            .unwrap_or_else(|| old_request.get_validation_type())
+            .to_string(),
         scopes: request
             .new_scopes
             .to_owned()
@@ -105,9 +166,13 @@ pub fn update_request_for_validation(
             None => old_request.quote.to_owned(),
             Some(new_quote) => new_quote,
         },
+        expires_at: old_request.expires_at.to_owned(),
+        claim_period: old_request.claim_period.to_owned(),
+        required_validator_count: old_request.required_validator_count,
+        access_routes: request.new_access_routes.to_owned(),
     };
     let ValidationRequestCreationResponse {
-        request_order: new_request_order,
+        request_order: mut new_request_order,
         messages,
         request_fee_msg,
     } = form_validation_request(
@@ -117,70 +182,1195 @@ pub fn update_request_for_validation(
         creation_request,
         ValidationRequestType::Update,
     )?;
-    // Update the existing request while continuing to validate it
+    // The request remains owned by its original requestor even when updated by the admin
+    new_request_order.owner = old_request.owner.clone();
+    for access_definition in new_request_order.access_definitions.iter_mut() {
+        access_definition.owner_address = old_request.owner.to_string();
+    }
+    // Unless replaced, the requestor's access definition is carried over unchanged, while any attached
+    // by validators whose claims have since been released are dropped
+    if request.new_access_routes.is_none() {
+        new_request_order.access_definitions = old_request
+            .get_access_definitions()
+            .iter()
+            .filter(|definition| {
+                definition.get_definition_type() == &AccessDefinitionType::Requestor
+            })
+            .cloned()
+            .collect();
+    }
     let request_update_metadata = get_validation_request_update(&old_request, &new_request_order);
+    if !request_update_metadata.has_metadata() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "No actual changes to the existing validation request with id [{}] were specified",
+                request.get_current_id(),
+            ),
+        }
+        .to_err();
+    }
+    // Update the existing request
     match maybe_new_storage_key {
-        Some(new_storage_key) => {
-            if request.get_current_id() == new_storage_key {
-                errors.push("cannot specify a new ID which is the same as the old ID".to_string());
-            } else if get_request(deps.storage, new_storage_key).is_ok() {
-                errors.push(format!(
-                    "a validation request with id [{}] already exists",
-                    new_storage_key
-                ));
-            }
-            // Create the new request
-            insert_request(deps.storage, &new_request_order)?;
-            // Delete the old request
+        Some(_) => {
+            // Delete the old request and create the new one
             delete_request_by_id(deps.storage, request.get_current_id())?;
+            insert_request(deps.storage, &new_request_order)?;
         }
-        None => {
-            if !request_update_metadata.has_metadata() {
-                return ContractError::InvalidRequest {
-                    message: format!(
-                        "No actual changes to the existing validation request with id [{}] were specified",
-                        request.get_current_id(),
-                    )
-                }.to_err();
-            }
-            // Update the existing request
-            store_request(deps.storage, &new_request_order, Some(&old_request))?;
+        None => store_request(deps.storage, &new_request_order, Some(&old_request))?,
+    }
+    // Create and return a response
+    let response = Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::UpdateValidationRequest)
+                .set_validation_request_id(new_request_order.get_id())
+                .set_validation_type(new_request_order.get_validation_type())
+                .set_additional_metadata(&request_update_metadata),
+        )
+        .add_messages(messages)
+        .add_messages(generate_payment_msg(&old_request.owner, &quote_decrease))
+        .set_data(to_binary(&new_request_order)?);
+    add_contract_fee_msg(response, FeeOperation::UpdateRequest, request_fee_msg)
+}
+
+pub fn delete_request_for_validation(
+    deps: DepsMutC,
+    _env: Env,
+    info: MessageInfo,
+    id: String,
+    force: Option<bool>,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let state = get_contract_info(deps.storage)?;
+    let request = get_request(deps.storage, &id).map_err(|err| ContractError::InvalidRequest {
+        message: format!("No validation request with id [{}] exists: {:?}", id, err),
+    })?;
+    if info.sender != request.owner && info.sender != state.admin {
+        return ContractError::Unauthorized {
+            reason: "must be the contract admin to delete a validation request owned by a different address"
+                .to_string(),
         }
+        .to_err();
     }
-    if !errors.is_empty() {
+    let accepted = matches!(
+        request.status,
+        ValidationRequestStatus::Fulfilled | ValidationRequestStatus::Disputed
+    ) || (request.is_open() && !request.get_claims().is_empty());
+    if accepted && (info.sender != state.admin || !force.unwrap_or(false)) {
         return ContractError::InvalidRequest {
-            message: errors.join(", "),
+            message: format!(
+                "validation request with id [{}] cannot be deleted because it has been accepted by a validator and its status is [{}], unless forced by the contract admin",
+                id, request.status
+            ),
         }
         .to_err();
     }
-    // Create and return a response
-    let mut response = Response::new()
-        .add_attributes(EventAttributes::new(EventType::UpdateValidationRequest))
-        // TODO: Add more attributes
-        .add_messages(messages);
-    if let Some(request_fee_msg) = request_fee_msg {
-        response = response
-            .add_attribute(
-                "request_creation_fee_charged",
-                get_custom_fee_amount_display(&request_fee_msg)?,
-            )
-            .add_message(request_fee_msg);
+    // Delete the request along with any results submitted for it
+    delete_request_by_id(deps.storage, &id)?;
+    delete_validation_results_by_request(deps.storage, &id)?;
+    // Refund the escrowed quote to the owner, unless it has already been paid out
+    let refund_msg = if request.holds_escrow() {
+        generate_payment_msg(&request.owner, request.get_quote())
+    } else {
+        None
+    };
+    // Construct the response
+    Response::new()
+        .add_messages(refund_msg)
+        .add_attributes(
+            EventAttributes::new(EventType::DeleteValidationRequest)
+                .set_validation_request_id(&request.id)
+                .set_validation_type(request.get_validation_type())
+                .set_validation_status(request.status.to_string()),
+        )
+        .set_data(to_binary(&request)?)
+        .to_ok()
+}
+
+pub fn cancel_request_for_validation(
+    deps: DepsMutC,
+    _env: Env,
+    info: MessageInfo,
+    id: String,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let state = get_contract_info(deps.storage)?;
+    let old_request =
+        get_request(deps.storage, &id).map_err(|err| ContractError::InvalidRequest {
+            message: format!("No validation request with id [{}] exists: {:?}", id, err),
+        })?;
+    if info.sender != old_request.owner && info.sender != state.admin {
+        return ContractError::Unauthorized {
+            reason: "must be the contract admin to cancel a validation request owned by a different address"
+                .to_string(),
+        }
+        .to_err();
     }
-    response.to_ok()
+    if !old_request.is_open() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot be cancelled because its status is [{}]",
+                id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    // Cancel the request, keeping it in storage so that it remains queryable
+    let mut new_request = old_request.clone();
+    new_request.status = ValidationRequestStatus::Cancelled;
+    store_request(deps.storage, &new_request, Some(&old_request))?;
+    // Compensate the validators which claimed the request, if any, by splitting the cancellation
+    // fraction of the quote evenly between them, and refund the remainder to the owner
+    let validators = old_request.get_validators();
+    let compensation_share = match validators.len() as u32 {
+        0 => vec![],
+        validator_count => divide_coins(
+            &multiply_coins(
+                old_request.get_quote(),
+                may_get_validation_definition(
+                    deps.storage,
+                    ValidationDefinition::generate_storage_key(old_request.get_validation_type()),
+                )
+                .map(|definition| definition.get_cancellation_fraction())
+                .unwrap_or_default(),
+            ),
+            validator_count,
+        ),
+    };
+    let mut messages = vec![];
+    let mut compensation = vec![];
+    for validator in &validators {
+        messages.extend(generate_payment_msg(validator, &compensation_share));
+        compensation.extend(compensation_share.iter().cloned());
+    }
+    messages.extend(generate_payment_msg(
+        &old_request.owner,
+        &subtract_coins(old_request.get_quote(), &compensation),
+    ));
+    // Construct the response
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    if !validators.is_empty() {
+        additional_metadata.add_metadata(
+            "compensated_validators",
+            validators
+                .iter()
+                .map(|validator| validator.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::CancelValidationRequest)
+                .set_validation_request_id(&new_request.id)
+                .set_validation_type(new_request.get_validation_type())
+                .set_validation_status(new_request.status.to_string())
+                .set_additional_metadata(&additional_metadata),
+        )
+        .add_messages(messages)
+        .set_data(to_binary(&new_request)?)
+        .to_ok()
 }
 
-pub fn delete_request_for_validation(
+pub fn accept_request_for_validation(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let old_request =
+        get_request(deps.storage, &id).map_err(|err| ContractError::InvalidRequest {
+            message: format!("No validation request with id [{}] exists: {:?}", id, err),
+        })?;
+    if old_request.status != ValidationRequestStatus::Requested {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot be accepted because its status is [{}]",
+                id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    if let Some(expires_at) = old_request
+        .maybe_get_expires_at()
+        .filter(|expires_at| expires_at.is_expired(&env.block))
+    {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot be accepted because it expired at [{}]",
+                id, expires_at
+            ),
+        }
+        .to_err();
+    }
+    if !old_request.allows_validator(&info.sender) {
+        return ContractError::Unauthorized {
+            reason: format!(
+                "[{}] is not an allowed validator for the validation request with id [{}]",
+                info.sender, id
+            ),
+        }
+        .to_err();
+    }
+    if old_request.maybe_get_claim(&info.sender).is_some() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "[{}] has already accepted the validation request with id [{}]",
+                info.sender, id
+            ),
+        }
+        .to_err();
+    }
+    let configuration = get_validator_configuration(
+        deps.storage,
+        ValidatorConfiguration::generate_storage_key(
+            &info.sender,
+            old_request.get_validation_type(),
+        ),
+    )
+    .map_err(|err| ContractError::InvalidRequest {
+        message: format!(
+            "no validator configuration for [{}] of validation type [{}] exists: {:?}",
+            info.sender,
+            old_request.get_validation_type(),
+            err
+        ),
+    })?;
+    // The validator's even share of the escrowed quote must cover every cost it has configured for the validation type
+    let costs = configuration
+        .get_validation_costs()
+        .iter()
+        .map(|cost| cost.get_coin())
+        .collect::<Vec<_>>();
+    let shortfall = subtract_coins(
+        &costs,
+        &divide_coins(
+            old_request.get_quote(),
+            old_request.get_required_validator_count(),
+        ),
+    );
+    if !shortfall.is_empty() {
+        return ContractError::InsufficientQuote {
+            message: format!(
+                "the share of the quote of validation request with id [{}] is short of the validation costs of [{}] by [{}]",
+                id,
+                info.sender,
+                display_coins(&shortfall)
+            ),
+        }
+        .to_err();
+    }
+    // Claim the request for the validator
+    let mut new_request = old_request.clone();
+    new_request.claims.push(ValidationRequestClaim {
+        validator: info.sender.clone(),
+        validation_costs: configuration.validation_costs,
+        expires_at: old_request
+            .maybe_get_claim_period()
+            .map(|claim_period| claim_period.after(&env.block)),
+        validated_scopes: vec![],
+    });
+    new_request
+        .refresh_status(get_agreeing_validation_results(deps.storage, &old_request).len() as u32);
+    store_request(deps.storage, &new_request, Some(&old_request))?;
+    let fee_msg = generate_contract_fee_msg(
+        FeeOperation::AcceptRequest,
+        &deps.as_ref(),
+        env.contract.address,
+    )?;
+    // Construct the response
+    add_contract_fee_msg(
+        Response::new()
+            .add_attributes(
+                EventAttributes::new(EventType::AcceptValidationRequest)
+                    .set_validation_request_id(&new_request.id)
+                    .set_validation_type(new_request.get_validation_type())
+                    .set_validator(&info.sender)
+                    .set_validation_status(new_request.status.to_string()),
+            )
+            .set_data(to_binary(&new_request)?),
+        FeeOperation::AcceptRequest,
+        fee_msg,
+    )
+}
+
+pub fn release_request_for_validation(
     deps: DepsMutC,
     _env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     id: String,
+    reason: String,
 ) -> EntryPointResponse {
-    // TODO: Complete
     // Validate the request
-    // Delete the definition
-    delete_request_by_id(deps.storage, id)?;
+    check_funds_are_empty(&info)?;
+    let old_request =
+        get_request(deps.storage, &id).map_err(|err| ContractError::InvalidRequest {
+            message: format!("No validation request with id [{}] exists: {:?}", id, err),
+        })?;
+    if !old_request.is_open() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot be released because its status is [{}]",
+                id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    match old_request.maybe_get_claim(&info.sender) {
+        None => {
+            return ContractError::Unauthorized {
+                reason: format!(
+                    "[{}] has not accepted the validation request with id [{}]",
+                    info.sender, id
+                ),
+            }
+            .to_err();
+        }
+        Some(claim) if old_request.is_claim_complete(claim) => {
+            return ContractError::InvalidRequest {
+                message: format!(
+                    "[{}] cannot release the validation request with id [{}] after submitting results for every scope",
+                    info.sender, id
+                ),
+            }
+            .to_err();
+        }
+        Some(_) => {}
+    }
+    if reason.trim().is_empty() {
+        return ContractError::InvalidRequest {
+            message: "a reason must be given for releasing a validation request".to_string(),
+        }
+        .to_err();
+    }
+    // Return the request to the marketplace, discarding any results submitted so far
+    let mut new_request = old_request.clone();
+    new_request.release(&info.sender, &reason);
+    new_request
+        .refresh_status(get_agreeing_validation_results(deps.storage, &new_request).len() as u32);
+    store_request(deps.storage, &new_request, Some(&old_request))?;
+    delete_validation_result(deps.storage, &id, &info.sender)?;
     // Construct the response
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("reason", reason);
     Response::new()
-        .add_attributes(EventAttributes::new(EventType::DeleteValidationRequest))
+        .add_attributes(
+            EventAttributes::new(EventType::ReleaseValidationRequest)
+                .set_validation_request_id(&new_request.id)
+                .set_validation_type(new_request.get_validation_type())
+                .set_validator(&info.sender)
+                .set_validation_status(new_request.status.to_string())
+                .set_additional_metadata(&additional_metadata),
+        )
+        .set_data(to_binary(&new_request)?)
         .to_ok()
 }
+
+pub fn expire_requests_for_validation(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    limit: Option<u32>,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    // Expire each request which has passed its expiration, and release each active claim which has passed
+    // its own, discarding any results submitted so far for the released claims
+    let mut expired_request_ids = vec![];
+    let mut released_request_ids = vec![];
+    let mut refund_msgs = vec![];
+    for old_request in get_expired_requests(deps.storage, &env.block, get_page_limit(limit))? {
+        let mut new_request = old_request.clone();
+        if old_request.is_expired(&env.block) {
+            new_request.status = ValidationRequestStatus::Expired;
+            refund_msgs.extend(generate_payment_msg(
+                &old_request.owner,
+                old_request.get_quote(),
+            ));
+            expired_request_ids.push(old_request.id.clone());
+        } else {
+            for claim in old_request.get_expired_claims(&env.block) {
+                delete_validation_result(deps.storage, &old_request.id, &claim.validator)?;
+                new_request.release(
+                    &claim.validator,
+                    "the claim expired before results were submitted",
+                );
+            }
+            new_request.refresh_status(
+                get_agreeing_validation_results(deps.storage, &new_request).len() as u32,
+            );
+            released_request_ids.push(old_request.id.clone());
+        }
+        store_request(deps.storage, &new_request, Some(&old_request))?;
+    }
+    // Construct the response
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    if !expired_request_ids.is_empty() {
+        additional_metadata.add_metadata("expired_request_ids", expired_request_ids.join(","));
+    }
+    if !released_request_ids.is_empty() {
+        additional_metadata.add_metadata("released_request_ids", released_request_ids.join(","));
+    }
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::ExpireValidationRequests)
+                .set_additional_metadata(&additional_metadata),
+        )
+        .add_messages(refund_msgs)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        accept_request_for_validation, cancel_request_for_validation,
+        create_request_for_validation, delete_request_for_validation,
+        expire_requests_for_validation, release_request_for_validation,
+        update_request_for_validation,
+    };
+    use crate::{
+        execute::validation_result::submit_validation_results,
+        storage::{
+            request::{get_request, may_get_request, store_request},
+            validation_definition::{get_validation_definition, store_validation_definition},
+            validation_result::get_validation_results_by_request,
+        },
+        test::{
+            arbitrary::{arb_addr, arb_coin, arb_entity, arb_quote, arb_validation_request},
+            helpers::{
+                set_up_claim, set_up_contract, set_up_contract_info, set_up_request,
+                set_up_validation_definition, set_up_validator_configuration,
+                single_attribute_for_key, TEST_ADMIN,
+            },
+        },
+        types::{
+            access_definition::{AccessDefinition, AccessDefinitionType},
+            access_route::AccessRoute,
+            core::error::ContractError,
+            expiration::{Duration, Expiration},
+            request::{
+                validation_request::{ValidationRequestStatus, ValidationRequestUpdate},
+                validation_result::ValidationResultSubmission,
+            },
+            validation_cost::ValidationCost,
+            validation_result::{ScopeValidationResult, ValidationVerdict},
+        },
+        util::{
+            constants::{
+                ADDITIONAL_METADATA_KEY, EVENT_TYPE_KEY, VALIDATION_REQUEST_ID_KEY,
+                VALIDATION_STATUS_KEY, VALIDATOR_ADDRESS_KEY,
+            },
+            escrow::{generate_payment_msg, multiply_coins, normalize_coins, subtract_coins},
+        },
+    };
+
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Addr, BankMsg, CosmosMsg, Decimal, Uint128};
+    use proptest::collection::vec;
+    use proptest::sample::size_range;
+    use proptest::{prop_assert, prop_assert_eq, prop_assume, proptest};
+    use provwasm_mocks::mock_dependencies;
+
+    proptest! {
+        #[test]
+        fn create_and_update_request_with_access_routes(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            let access_route = AccessRoute { route: "grpcs://data.example.com".to_string(), name: Some("data".to_string()) };
+            let mut request = request;
+            request.access_routes = Some(vec![AccessRoute { route: " ".to_string(), name: None }]);
+            match create_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &request.quote), request.clone()) {
+                Err(ContractError::InvalidRequest { .. }) => {},
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+            request.access_routes = Some(vec![access_route.clone()]);
+            let response = create_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &request.quote), request);
+            prop_assert!(response.is_ok(), "creating a validation request with access routes produced an error: {}", response.unwrap_err());
+            let requestor_definition = AccessDefinition {
+                owner_address: owner.to_string(),
+                access_routes: vec![access_route],
+                definition_type: AccessDefinitionType::Requestor,
+            };
+            prop_assert_eq!(vec![requestor_definition.clone()], get_request(&deps.storage, &id).unwrap().access_definitions);
+
+            let new_access_route = AccessRoute { route: "https://data.example.com".to_string(), name: None };
+            let update = ValidationRequestUpdate {
+                current_id: id.clone(),
+                new_id: None,
+                new_validation_type: None,
+                new_scopes: None,
+                new_allowed_validators: None,
+                new_quote: None,
+                new_access_routes: Some(vec![new_access_route.clone()]),
+            };
+            let response = update_request_for_validation(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), update);
+            prop_assert!(response.is_ok(), "updating the access routes of a validation request produced an error: {}", response.unwrap_err());
+            let requestor_definition = AccessDefinition { access_routes: vec![new_access_route], ..requestor_definition };
+            prop_assert_eq!(
+                vec![requestor_definition.clone()],
+                get_request(&deps.storage, &id).unwrap().access_definitions
+            );
+
+            // A validator's access definition from a released claim is dropped by an update which keeps the access routes
+            let old_request = get_request(&deps.storage, &id).unwrap();
+            let mut released_request = old_request.clone();
+            released_request.access_definitions.push(AccessDefinition {
+                owner_address: "validator".to_string(),
+                access_routes: vec![AccessRoute { route: "grpcs://validator.example.com".to_string(), name: None }],
+                definition_type: AccessDefinitionType::Verifier,
+            });
+            store_request(deps.as_mut().storage, &released_request, Some(&old_request)).unwrap();
+            let update = ValidationRequestUpdate {
+                current_id: id.clone(),
+                new_id: None,
+                new_validation_type: None,
+                new_scopes: None,
+                new_allowed_validators: Some(vec![Addr::unchecked("validator")]),
+                new_quote: None,
+                new_access_routes: None,
+            };
+            let response = update_request_for_validation(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), update);
+            prop_assert!(response.is_ok(), "updating the allowed validators of a validation request produced an error: {}", response.unwrap_err());
+            prop_assert_eq!(vec![requestor_definition], get_request(&deps.storage, &id).unwrap().access_definitions);
+        }
+
+        #[test]
+        fn accept_request_with_configured_validator(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let response = accept_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone());
+            prop_assert!(response.is_ok(), "accepting a validation request produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("accept_validation_request", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            prop_assert_eq!(validator.as_str(), single_attribute_for_key(&response, VALIDATOR_ADDRESS_KEY));
+            prop_assert_eq!("pending", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+
+            let stored_request = get_request(&deps.storage, id).unwrap();
+            prop_assert_eq!(&ValidationRequestStatus::Pending, &stored_request.status);
+            prop_assert_eq!(Some(&validator), stored_request.get_validators().first().copied());
+        }
+
+        #[test]
+        fn accept_request_with_disallowed_validator(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            allowed_validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            prop_assume!(validator != allowed_validator);
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let mut request = request;
+            request.allowed_validators = Some(vec![allowed_validator]);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let response = accept_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone());
+            match response {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            prop_assert_eq!(ValidationRequestStatus::Requested, get_request(&deps.storage, id).unwrap().status);
+        }
+
+        #[test]
+        fn accept_request_without_validator_configuration(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let response = accept_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id);
+            match response {
+                Err(ContractError::InvalidRequest { message }) => {
+                    prop_assert!(message.contains("no validator configuration"))
+                },
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn accept_request_with_insufficient_quote(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            destination in arb_entity(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![
+                ValidationCost { amount: Uint128::new(5), denom: "d".to_string(), destination },
+            ]);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let response = accept_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone());
+            match response {
+                Err(ContractError::InsufficientQuote { message }) => {
+                    prop_assert!(message.contains("5d"), "expected the shortfall to be listed in: {}", message)
+                },
+                other => prop_assert!(false, "expected an insufficient quote error but got: {:?}", other),
+            }
+            prop_assert_eq!(ValidationRequestStatus::Requested, get_request(&deps.storage, id).unwrap().status);
+        }
+
+        #[test]
+        fn create_request_with_unknown_validation_type(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = mock_dependencies(&[]);
+            set_up_contract_info(&mut deps);
+            let id = request.id.clone();
+
+            let response = create_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &request.quote), request);
+            match response {
+                Err(ContractError::InvalidRequest { message }) => {
+                    prop_assert!(message.contains("no validation definition"))
+                },
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+            prop_assert!(may_get_request(&deps.storage, id).is_none());
+        }
+
+        #[test]
+        fn create_request_with_disabled_validation_type(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = mock_dependencies(&[]);
+            set_up_contract_info(&mut deps);
+            set_up_validation_definition(&mut deps, &request.validation_type, false);
+            let id = request.id.clone();
+
+            let response = create_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &request.quote), request);
+            match response {
+                Err(ContractError::InvalidRequest { message }) => {
+                    prop_assert!(message.contains("is not enabled"))
+                },
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+            prop_assert!(may_get_request(&deps.storage, id).is_none());
+        }
+
+        #[test]
+        fn create_request_with_mismatched_funds(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+            extra_funds in arb_coin(),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let mut funds = request.quote.clone();
+            funds.push(extra_funds);
+            let id = request.id.clone();
+
+            let response = create_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &funds), request);
+            match response {
+                Err(ContractError::InvalidFunds { .. }) => {},
+                other => prop_assert!(false, "expected an invalid funds error but got: {:?}", other),
+            }
+            prop_assert!(may_get_request(&deps.storage, id).is_none());
+        }
+
+        #[test]
+        fn update_request_quote_adjusts_escrow(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+            new_quote in arb_quote(),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            let old_quote = request.quote.clone();
+            prop_assume!(normalize_coins(&old_quote) != normalize_coins(&new_quote));
+            set_up_request(&mut deps, &owner, request);
+
+            let top_up = subtract_coins(&new_quote, &old_quote);
+            let refund = subtract_coins(&old_quote, &new_quote);
+            let update = ValidationRequestUpdate {
+                current_id: id.clone(),
+                new_id: None,
+                new_validation_type: None,
+                new_scopes: None,
+                new_allowed_validators: None,
+                new_quote: Some(new_quote.clone()),
+                new_access_routes: None,
+            };
+            let response = update_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &top_up), update);
+            prop_assert!(response.is_ok(), "updating the quote of a validation request produced an error: {}", response.unwrap_err());
+            let refund_msgs = response
+                .unwrap()
+                .messages
+                .into_iter()
+                .filter_map(|msg| match msg.msg {
+                    CosmosMsg::Bank(BankMsg::Send { to_address, amount }) => Some((to_address, amount)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if refund.is_empty() {
+                prop_assert!(refund_msgs.is_empty());
+            } else {
+                prop_assert_eq!(vec![(owner.to_string(), refund)], refund_msgs);
+            }
+            prop_assert_eq!(new_quote, get_request(&deps.storage, id).unwrap().quote);
+        }
+
+        #[test]
+        fn delete_request_refunds_quote(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            prop_assume!(!request.quote.is_empty());
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let response = delete_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), id.clone(), None);
+            prop_assert!(response.is_ok(), "deleting a validation request produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!(1, response.messages.len());
+            prop_assert_eq!(
+                &CosmosMsg::Bank(BankMsg::Send { to_address: owner.to_string(), amount: normalize_coins(&quote) }),
+                &response.messages[0].msg
+            );
+            prop_assert!(may_get_request(&deps.storage, id).is_none());
+        }
+
+        #[test]
+        fn delete_request_of_other_owner(
+            owner in arb_addr(),
+            other_address in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            prop_assume!(owner != other_address && other_address.as_str() != TEST_ADMIN);
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let response = delete_request_for_validation(deps.as_mut(), mock_env(), mock_info(other_address.as_str(), &[]), id.clone(), Some(true));
+            match response {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            prop_assert!(may_get_request(&deps.storage, id).is_some());
+        }
+
+        #[test]
+        fn delete_pending_request_requires_admin_force(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            prop_assume!(owner.as_str() != TEST_ADMIN);
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            for (sender, force) in [(owner.as_str(), Some(true)), (TEST_ADMIN, None)] {
+                let response = delete_request_for_validation(deps.as_mut(), mock_env(), mock_info(sender, &[]), id.clone(), force);
+                match response {
+                    Err(ContractError::InvalidRequest { .. }) => {},
+                    other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+                }
+            }
+            let response = delete_request_for_validation(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), id.clone(), Some(true));
+            prop_assert!(response.is_ok(), "force deleting a pending validation request produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!(id.as_str(), single_attribute_for_key(&response, VALIDATION_REQUEST_ID_KEY));
+            prop_assert_eq!(
+                generate_payment_msg(&owner, &quote).into_iter().collect::<Vec<_>>(),
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            prop_assert!(may_get_request(&deps.storage, id).is_none());
+        }
+
+        #[test]
+        fn recreate_deleted_request_without_its_results(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            let submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address: owner.clone(),
+                scope_results: request.scopes.iter().map(|scope| ScopeValidationResult { scope: scope.clone(), verdict: ValidationVerdict::Pass }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            set_up_request(&mut deps, &owner, request.clone());
+            set_up_claim(&mut deps, &validator, &id);
+            submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission.clone())
+                .expect("submitting validation results for an accepted request should succeed");
+            prop_assert_eq!(1, get_validation_results_by_request(&deps.storage, &id, None, None).items.len());
+
+            delete_request_for_validation(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), id.clone(), Some(true))
+                .expect("force deleting a fulfilled validation request should succeed");
+            prop_assert!(get_validation_results_by_request(&deps.storage, &id, None, None).items.is_empty());
+
+            set_up_request(&mut deps, &owner, request);
+            prop_assert!(get_validation_results_by_request(&deps.storage, &id, None, None).items.is_empty());
+            set_up_claim(&mut deps, &validator, &id);
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission);
+            prop_assert!(response.is_ok(), "submitting results for a recreated validation request produced an error: {}", response.unwrap_err());
+            prop_assert_eq!(ValidationRequestStatus::Fulfilled, get_request(&deps.storage, &id).unwrap().status);
+        }
+
+        #[test]
+        fn update_request_of_other_owner(
+            owner in arb_addr(),
+            other_address in arb_addr(),
+            request in arb_validation_request(None),
+            new_scopes in vec(arb_addr(), size_range(1..10)),
+        ) {
+            prop_assume!(owner != other_address && other_address.as_str() != TEST_ADMIN);
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let update = ValidationRequestUpdate {
+                current_id: id,
+                new_id: None,
+                new_validation_type: None,
+                new_scopes: Some(new_scopes),
+                new_allowed_validators: None,
+                new_quote: None,
+                new_access_routes: None,
+            };
+            match update_request_for_validation(deps.as_mut(), mock_env(), mock_info(other_address.as_str(), &[]), update) {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn update_pending_request(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            new_scopes in vec(arb_addr(), size_range(1..10)),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            let update = ValidationRequestUpdate {
+                current_id: id.clone(),
+                new_id: None,
+                new_validation_type: None,
+                new_scopes: Some(new_scopes),
+                new_allowed_validators: None,
+                new_quote: None,
+                new_access_routes: None,
+            };
+            match update_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), update) {
+                Err(ContractError::InvalidRequest { message }) => prop_assert!(message.contains("pending")),
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+            prop_assert_eq!(ValidationRequestStatus::Pending, get_request(&deps.storage, id).unwrap().status);
+        }
+
+        #[test]
+        fn update_request_to_existing_id(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+            other_request in arb_validation_request(None),
+        ) {
+            prop_assume!(request.id != other_request.id);
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            let other_id = other_request.id.clone();
+            let mut other_request = other_request;
+            other_request.validation_type = request.validation_type.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_request(&mut deps, &owner, other_request);
+            let old_request = get_request(&deps.storage, &id).unwrap();
+            let old_other_request = get_request(&deps.storage, &other_id).unwrap();
+
+            let update = ValidationRequestUpdate {
+                current_id: id.clone(),
+                new_id: Some(other_id.clone()),
+                new_validation_type: None,
+                new_scopes: None,
+                new_allowed_validators: None,
+                new_quote: None,
+                new_access_routes: None,
+            };
+            match update_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), update) {
+                Err(ContractError::ExistingId { .. }) => {},
+                other => prop_assert!(false, "expected an existing id error but got: {:?}", other),
+            }
+            prop_assert_eq!(old_request, get_request(&deps.storage, id).unwrap());
+            prop_assert_eq!(old_other_request, get_request(&deps.storage, other_id).unwrap());
+        }
+
+        #[test]
+        fn update_request_scopes_as_admin(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+            new_scopes in vec(arb_addr(), size_range(1..10)),
+        ) {
+            prop_assume!(request.scopes != new_scopes);
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let update = ValidationRequestUpdate {
+                current_id: id.clone(),
+                new_id: None,
+                new_validation_type: None,
+                new_scopes: Some(new_scopes.clone()),
+                new_allowed_validators: None,
+                new_quote: None,
+                new_access_routes: None,
+            };
+            let response = update_request_for_validation(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), update);
+            prop_assert!(response.is_ok(), "updating a validation request as the admin produced an error: {}", response.unwrap_err());
+            prop_assert!(single_attribute_for_key(&response.unwrap(), ADDITIONAL_METADATA_KEY).contains("new_scopes="));
+            let stored_request = get_request(&deps.storage, id).unwrap();
+            prop_assert_eq!(new_scopes, stored_request.scopes);
+            prop_assert_eq!(owner, stored_request.owner);
+        }
+
+        #[test]
+        fn expire_requests_refunds_expired_and_releases_claims(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            expiring_request in arb_validation_request(None),
+            claimed_request in arb_validation_request(None),
+        ) {
+            prop_assume!(expiring_request.id != claimed_request.id);
+            let mut deps = set_up_contract(&expiring_request.validation_type);
+            set_up_validation_definition(&mut deps, &claimed_request.validation_type, true);
+            set_up_validator_configuration(&mut deps, &validator, &claimed_request.validation_type, vec![]);
+            let mut env = mock_env();
+            let mut expiring_request = expiring_request;
+            expiring_request.expires_at = Some(Expiration::AtHeight(env.block.height + 5));
+            let mut claimed_request = claimed_request;
+            claimed_request.claim_period = Some(Duration::Height(5));
+            let expiring_id = expiring_request.id.clone();
+            let claimed_id = claimed_request.id.clone();
+            let expiring_quote = expiring_request.quote.clone();
+            create_request_for_validation(deps.as_mut(), env.clone(), mock_info(owner.as_str(), &expiring_request.quote), expiring_request)
+                .expect("creating a validation request with a future expiration should succeed");
+            create_request_for_validation(deps.as_mut(), env.clone(), mock_info(owner.as_str(), &claimed_request.quote), claimed_request)
+                .expect("creating a validation request with a claim period should succeed");
+            accept_request_for_validation(deps.as_mut(), env.clone(), mock_info(validator.as_str(), &[]), claimed_id.clone())
+                .expect("accepting a validation request with a configured validator should succeed");
+
+            let response = expire_requests_for_validation(deps.as_mut(), env.clone(), mock_info(owner.as_str(), &[]), None)
+                .expect("expiring validation requests before any expiration should succeed");
+            prop_assert!(response.messages.is_empty());
+            prop_assert_eq!(ValidationRequestStatus::Pending, get_request(&deps.storage, &claimed_id).unwrap().status);
+
+            env.block.height += 10;
+            let response = expire_requests_for_validation(deps.as_mut(), env.clone(), mock_info(validator.as_str(), &[]), None);
+            prop_assert!(response.is_ok(), "expiring validation requests produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("expire_validation_requests", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            let metadata = single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY);
+            let expired_entry = format!("expired_request_ids={}", expiring_id);
+            let released_entry = format!("released_request_ids={}", claimed_id);
+            prop_assert!(metadata.contains(&expired_entry), "missing expired request in metadata: {}", metadata);
+            prop_assert!(metadata.contains(&released_entry), "missing released request in metadata: {}", metadata);
+            prop_assert_eq!(
+                generate_payment_msg(&owner, &expiring_quote).into_iter().collect::<Vec<_>>(),
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(ValidationRequestStatus::Expired, get_request(&deps.storage, &expiring_id).unwrap().status);
+            let released_request = get_request(&deps.storage, &claimed_id).unwrap();
+            prop_assert_eq!(&ValidationRequestStatus::Requested, &released_request.status);
+            prop_assert!(released_request.get_claims().is_empty());
+
+            match accept_request_for_validation(deps.as_mut(), env.clone(), mock_info(validator.as_str(), &[]), expiring_id) {
+                Err(ContractError::InvalidRequest { .. }) => {},
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+            let response = expire_requests_for_validation(deps.as_mut(), env, mock_info(owner.as_str(), &[]), None)
+                .expect("expiring validation requests with nothing left to expire should succeed");
+            prop_assert!(response.messages.is_empty());
+        }
+
+        #[test]
+        fn create_request_already_expired(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let env = mock_env();
+            let mut request = request;
+            request.expires_at = Some(Expiration::AtTime(env.block.time));
+            let id = request.id.clone();
+            match create_request_for_validation(deps.as_mut(), env, mock_info(owner.as_str(), &request.quote), request) {
+                Err(ContractError::InvalidRequest { .. }) => {},
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+            prop_assert!(may_get_request(&deps.storage, id).is_none());
+        }
+
+        #[test]
+        fn cancel_unclaimed_request_refunds_owner(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let id = request.id.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+
+            let response = cancel_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), id.clone());
+            prop_assert!(response.is_ok(), "cancelling a validation request produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("cancel_validation_request", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            prop_assert_eq!("cancelled", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            prop_assert_eq!(
+                generate_payment_msg(&owner, &quote).into_iter().collect::<Vec<_>>(),
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(ValidationRequestStatus::Cancelled, get_request(&deps.storage, &id).unwrap().status);
+            match cancel_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), id) {
+                Err(ContractError::InvalidRequest { .. }) => {},
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn cancel_claimed_request_compensates_validator(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            percent in 0..=100u64,
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let mut definition = get_validation_definition(&deps.storage, request.validation_type.to_lowercase()).unwrap();
+            definition.cancellation_fraction = Some(Decimal::percent(percent));
+            store_validation_definition(deps.as_mut().storage, &definition, None)
+                .expect("expected validation definition to save successfully");
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let mut request = request;
+            request.quote.push(coin(200, "d"));
+            let id = request.id.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            match cancel_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone()) {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            let response = cancel_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), id.clone());
+            prop_assert!(response.is_ok(), "cancelling a claimed validation request produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!(format!("[compensated_validators={}]", validator), single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY));
+            let compensation = multiply_coins(&quote, Decimal::percent(percent));
+            let mut expected_msgs = generate_payment_msg(&validator, &compensation).into_iter().collect::<Vec<_>>();
+            expected_msgs.extend(generate_payment_msg(&owner, &subtract_coins(&quote, &compensation)));
+            prop_assert_eq!(
+                expected_msgs,
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            let cancelled_request = get_request(&deps.storage, &id).unwrap();
+            prop_assert_eq!(Some(&validator), cancelled_request.get_validators().first().copied());
+            prop_assert_eq!(ValidationRequestStatus::Cancelled, cancelled_request.status);
+        }
+
+        #[test]
+        fn release_claimed_request_records_reason(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            reason in "[a-z]+( [a-z]+)*",
+        ) {
+            prop_assume!(owner != validator);
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            set_up_request(&mut deps, &owner, request);
+            match release_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone(), reason.clone()) {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            set_up_claim(&mut deps, &validator, &id);
+            match release_request_for_validation(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), id.clone(), reason.clone()) {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+
+            let response = release_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone(), reason.clone());
+            prop_assert!(response.is_ok(), "releasing a validation request produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("release_validation_request", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            prop_assert_eq!(validator.as_str(), single_attribute_for_key(&response, VALIDATOR_ADDRESS_KEY));
+            prop_assert_eq!("requested", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            prop_assert_eq!(format!("[reason={}]", reason), single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY));
+            prop_assert!(response.messages.is_empty());
+
+            let released_request = get_request(&deps.storage, &id).unwrap();
+            prop_assert_eq!(ValidationRequestStatus::Requested, released_request.status.clone());
+            prop_assert!(released_request.get_claims().is_empty());
+            let release = released_request.maybe_get_last_release().unwrap();
+            prop_assert_eq!(&validator, &release.validator);
+            prop_assert_eq!(reason.as_str(), release.get_reason());
+        }
+
+        #[test]
+        fn release_one_of_several_claims(
+            owner in arb_addr(),
+            validators in vec(arb_addr(), size_range(3)),
+            mut request in arb_validation_request(None),
+        ) {
+            prop_assume!(validators.iter().all(|validator| validator != &owner));
+            prop_assume!(validators[0] != validators[1] && validators[1] != validators[2] && validators[0] != validators[2]);
+            request.required_validator_count = Some(2);
+            let mut deps = set_up_contract(&request.validation_type);
+            for validator in &validators {
+                set_up_validator_configuration(&mut deps, validator, &request.validation_type, vec![]);
+            }
+            let id = request.id.clone();
+            let submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address: owner.clone(),
+                scope_results: request.scopes.iter().map(|scope| ScopeValidationResult { scope: scope.clone(), verdict: ValidationVerdict::Pass }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validators[0], &id);
+            submit_validation_results(deps.as_mut(), mock_env(), mock_info(validators[0].as_str(), &[]), submission)
+                .expect("submitting validation results for an accepted request should succeed");
+            set_up_claim(&mut deps, &validators[1], &id);
+            prop_assert_eq!(ValidationRequestStatus::Pending, get_request(&deps.storage, &id).unwrap().status);
+
+            let response = release_request_for_validation(deps.as_mut(), mock_env(), mock_info(validators[1].as_str(), &[]), id.clone(), "unavailable".to_string());
+            prop_assert!(response.is_ok(), "releasing a validation request produced an error: {}", response.unwrap_err());
+            let released_request = get_request(&deps.storage, &id).unwrap();
+            prop_assert_eq!(ValidationRequestStatus::Requested, released_request.status.clone());
+            prop_assert_eq!(vec![&validators[0]], released_request.get_validators());
+            // The results already submitted still count, so a single further claim can complete the request
+            set_up_claim(&mut deps, &validators[2], &id);
+            prop_assert_eq!(ValidationRequestStatus::Pending, get_request(&deps.storage, &id).unwrap().status);
+        }
+    }
+}
@@ -0,0 +1,950 @@
+use std::collections::HashSet;
+
+use crate::{
+    storage::{
+        contract_info::get_contract_info,
+        request::{get_request, store_request},
+        validation_definition::may_get_validation_definition,
+        validation_result::{
+            get_agreeing_validation_results, may_get_validation_result, store_validation_result,
+        },
+    },
+    types::{
+        access_definition::{AccessDefinition, AccessDefinitionType},
+        core::error::ContractError,
+        fee::FeeOperation,
+        request::{
+            validation_request::{
+                DisputeResolution, ValidationRequestDispute, ValidationRequestOrder,
+                ValidationRequestSettlement, ValidationRequestStatus,
+            },
+            validation_result::ValidationResultSubmission,
+        },
+        validation_definition::ValidationDefinition,
+        validation_result::{ScopeValidationAttribute, ValidationResult},
+    },
+    util::{
+        aliases::{ContractResult, DepsMutC, EntryPointResponse},
+        create_request_utilities::validate_access_routes,
+        escrow::{generate_settlement_msgs, multiply_coins, subtract_coins},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        fees::{add_contract_fee_msg, generate_contract_fee_msg},
+        helpers::check_funds_are_empty,
+    },
+};
+
+use cosmwasm_std::{to_binary, Addr, Decimal, Env, MessageInfo, Response};
+use provwasm_std::add_json_attribute;
+use result_extensions::ResultExtensions;
+
+pub fn submit_validation_results(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    submission: ValidationResultSubmission,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let old_request = get_request(deps.storage, submission.get_request_id()).map_err(|err| {
+        ContractError::InvalidRequest {
+            message: format!(
+                "No validation request with id [{}] exists: {:?}",
+                submission.get_request_id(),
+                err
+            ),
+        }
+    })?;
+    if !old_request.is_open() {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "results cannot be submitted for validation request with id [{}] because its status is [{}]",
+                old_request.id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    let claim = match old_request.maybe_get_claim(&info.sender) {
+        Some(claim) => claim,
+        None => {
+            return ContractError::Unauthorized {
+                reason: format!(
+                    "[{}] has not accepted the validation request with id [{}]",
+                    info.sender, old_request.id
+                ),
+            }
+            .to_err();
+        }
+    };
+    if old_request.is_expired(&env.block)
+        || claim
+            .maybe_get_expires_at()
+            .is_some_and(|expires_at| expires_at.is_expired(&env.block))
+    {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "results cannot be submitted for validation request with id [{}] because it or its claim has expired",
+                old_request.id
+            ),
+        }
+        .to_err();
+    }
+    validate_scope_results(&old_request, &info.sender, &submission)?;
+    let old_result = may_get_validation_result(deps.storage, &old_request.id, &info.sender);
+    if let Some(old_result) = &old_result {
+        if old_result.results_scope_address != submission.results_scope_address {
+            return ContractError::InvalidRequest {
+                message: format!(
+                    "results for validation request with id [{}] must be submitted to the same results scope [{}] as before",
+                    old_request.id, old_result.results_scope_address
+                ),
+            }
+            .to_err();
+        }
+    }
+    // Write the verdict for each submitted scope as an attribute on the scope, if a name was bound for the
+    // validation definition
+    let definition = may_get_validation_definition(
+        deps.storage,
+        ValidationDefinition::generate_storage_key(old_request.get_validation_type()),
+    );
+    let mut attribute_msgs = vec![];
+    if let Some(bound_name) = definition
+        .as_ref()
+        .and_then(|definition| definition.maybe_get_bound_name())
+    {
+        for scope_result in submission.get_scope_results() {
+            attribute_msgs.push(add_json_attribute(
+                scope_result.scope.clone(),
+                bound_name,
+                &ScopeValidationAttribute {
+                    request_id: old_request.id.clone(),
+                    validator: info.sender.clone(),
+                    results_scope_address: submission.results_scope_address.clone(),
+                    verdict: scope_result.verdict.clone(),
+                },
+            )?);
+        }
+    }
+    // Store the results, merged with any submitted previously
+    let mut result = old_result.clone().unwrap_or(ValidationResult {
+        request_id: submission.request_id,
+        validator: info.sender.clone(),
+        results_scope_address: submission.results_scope_address,
+        scope_results: vec![],
+        findings: None,
+    });
+    result.scope_results.extend(submission.scope_results);
+    if submission.findings.is_some() {
+        result.findings = submission.findings;
+    }
+    store_validation_result(deps.storage, &result, old_result.as_ref())?;
+    // Record the progress of the claim, and fulfill the request once the required number of
+    // validators have submitted agreeing results for every scope
+    let mut new_request = old_request.clone();
+    if let Some(claim) = new_request
+        .claims
+        .iter_mut()
+        .find(|claim| claim.validator == info.sender)
+    {
+        claim.validated_scopes = result
+            .get_scope_results()
+            .iter()
+            .map(|scope_result| scope_result.scope.clone())
+            .collect();
+    }
+    // Attach the validator's access routes to the request, replacing any it attached previously
+    if let Some(access_routes) = submission.access_routes {
+        new_request.access_definitions.retain(|definition| {
+            definition.definition_type != AccessDefinitionType::Verifier
+                || definition.owner_address != info.sender.as_str()
+        });
+        if !access_routes.is_empty() {
+            new_request.access_definitions.push(AccessDefinition {
+                owner_address: info.sender.to_string(),
+                access_routes,
+                definition_type: AccessDefinitionType::Verifier,
+            });
+        }
+    }
+    let agreeing_results = get_agreeing_validation_results(deps.storage, &new_request);
+    new_request.refresh_status(agreeing_results.len() as u32);
+    // Once fulfilled, record the agreeing validators between which the escrowed quote is split, and hold
+    // it for the dispute period of the validation definition, if it has one
+    let mut payment_msgs = vec![];
+    if new_request.status == ValidationRequestStatus::Fulfilled {
+        let dispute_deadline = definition
+            .as_ref()
+            .and_then(|definition| definition.maybe_get_dispute_period())
+            .map(|dispute_period| dispute_period.after(&env.block));
+        let holds_payment = dispute_deadline.is_some();
+        new_request.settlement = Some(ValidationRequestSettlement {
+            validators: agreeing_results
+                .iter()
+                .map(|result| result.validator.clone())
+                .collect(),
+            paid: !holds_payment,
+            dispute_deadline,
+        });
+        if !holds_payment {
+            payment_msgs = generate_settlement_msgs(&new_request, new_request.get_quote());
+        }
+    }
+    store_request(deps.storage, &new_request, Some(&old_request))?;
+    let fee_msg = generate_contract_fee_msg(
+        FeeOperation::SubmitResults,
+        &deps.as_ref(),
+        env.contract.address,
+    )?;
+    // Construct the response
+    add_contract_fee_msg(
+        Response::new()
+            .add_messages(attribute_msgs)
+            .add_messages(payment_msgs)
+            .add_attributes(
+                EventAttributes::new(EventType::SubmitValidationResults)
+                    .set_validation_request_id(&new_request.id)
+                    .set_validator(&info.sender)
+                    .set_results_scope_address(&result.results_scope_address)
+                    .set_validation_status(new_request.status.to_string()),
+            )
+            .set_data(to_binary(&result)?),
+        FeeOperation::SubmitResults,
+        fee_msg,
+    )
+}
+
+pub fn dispute_validation_result(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+    reason: String,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let old_request =
+        get_request(deps.storage, &id).map_err(|err| ContractError::InvalidRequest {
+            message: format!("No validation request with id [{}] exists: {:?}", id, err),
+        })?;
+    if info.sender != old_request.owner {
+        return ContractError::Unauthorized {
+            reason: "must be the owner of a validation request to dispute its results".to_string(),
+        }
+        .to_err();
+    }
+    if old_request.status != ValidationRequestStatus::Fulfilled
+        || !old_request
+            .maybe_get_settlement()
+            .is_some_and(|settlement| settlement.is_disputable(&env.block))
+    {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "the results of validation request with id [{}] cannot be disputed because its status is [{}] or its dispute period has passed",
+                id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    if reason.trim().is_empty() {
+        return ContractError::InvalidRequest {
+            message: "a reason must be given for disputing validation results".to_string(),
+        }
+        .to_err();
+    }
+    // Dispute the results, holding the escrowed quote until the dispute is resolved
+    let mut new_request = old_request.clone();
+    new_request.status = ValidationRequestStatus::Disputed;
+    new_request.dispute = Some(ValidationRequestDispute {
+        reason: reason.clone(),
+        resolution: None,
+    });
+    store_request(deps.storage, &new_request, Some(&old_request))?;
+    // Construct the response
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("reason", reason);
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::DisputeValidationResult)
+                .set_validation_request_id(&new_request.id)
+                .set_validation_status(new_request.status.to_string())
+                .set_additional_metadata(&additional_metadata),
+        )
+        .set_data(to_binary(&new_request)?)
+        .to_ok()
+}
+
+pub fn resolve_dispute(
+    deps: DepsMutC,
+    _env: Env,
+    info: MessageInfo,
+    id: String,
+    resolution: DisputeResolution,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let state = get_contract_info(deps.storage)?;
+    if info.sender != state.admin {
+        return ContractError::Unauthorized {
+            reason: "must be the contract admin to resolve a dispute".to_string(),
+        }
+        .to_err();
+    }
+    let old_request =
+        get_request(deps.storage, &id).map_err(|err| ContractError::InvalidRequest {
+            message: format!("No validation request with id [{}] exists: {:?}", id, err),
+        })?;
+    if old_request.status != ValidationRequestStatus::Disputed {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot have a dispute resolved because its status is [{}]",
+                id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    let payout = match &resolution {
+        DisputeResolution::PayValidators => old_request.get_quote().to_vec(),
+        DisputeResolution::RefundOwner => vec![],
+        DisputeResolution::Split { owner_fraction } => {
+            if *owner_fraction > Decimal::one() {
+                return ContractError::InvalidRequest {
+                    message: format!(
+                        "the owner fraction of a split must be between zero and one, but was [{}]",
+                        owner_fraction
+                    ),
+                }
+                .to_err();
+            }
+            subtract_coins(
+                old_request.get_quote(),
+                &multiply_coins(old_request.get_quote(), *owner_fraction),
+            )
+        }
+    };
+    // Resolve the dispute, returning the request to fulfilled and paying out its escrowed quote
+    let mut new_request = old_request.clone();
+    new_request.status = ValidationRequestStatus::Fulfilled;
+    if let Some(dispute) = new_request.dispute.as_mut() {
+        dispute.resolution = Some(resolution.clone());
+    }
+    if let Some(settlement) = new_request.settlement.as_mut() {
+        settlement.paid = true;
+    }
+    store_request(deps.storage, &new_request, Some(&old_request))?;
+    // Construct the response
+    let mut additional_metadata = EventAdditionalMetadata::new();
+    additional_metadata.add_metadata("resolution", resolution.to_string());
+    Response::new()
+        .add_messages(generate_settlement_msgs(&new_request, &payout))
+        .add_attributes(
+            EventAttributes::new(EventType::ResolveDispute)
+                .set_validation_request_id(&new_request.id)
+                .set_validation_status(new_request.status.to_string())
+                .set_additional_metadata(&additional_metadata),
+        )
+        .set_data(to_binary(&new_request)?)
+        .to_ok()
+}
+
+pub fn settle_validation_request(
+    deps: DepsMutC,
+    env: Env,
+    info: MessageInfo,
+    id: String,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let old_request =
+        get_request(deps.storage, &id).map_err(|err| ContractError::InvalidRequest {
+            message: format!("No validation request with id [{}] exists: {:?}", id, err),
+        })?;
+    if old_request.status != ValidationRequestStatus::Fulfilled
+        || !old_request.holds_escrow()
+        || old_request
+            .maybe_get_settlement()
+            .is_some_and(|settlement| settlement.is_disputable(&env.block))
+    {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validation request with id [{}] cannot be settled because its status is [{}], it has already been settled, or its dispute period has not yet passed",
+                id, old_request.status
+            ),
+        }
+        .to_err();
+    }
+    // Settle the request, paying out its escrowed quote to its validators
+    let mut new_request = old_request.clone();
+    if let Some(settlement) = new_request.settlement.as_mut() {
+        settlement.paid = true;
+    }
+    store_request(deps.storage, &new_request, Some(&old_request))?;
+    // Construct the response
+    Response::new()
+        .add_messages(generate_settlement_msgs(
+            &new_request,
+            new_request.get_quote(),
+        ))
+        .add_attributes(
+            EventAttributes::new(EventType::SettleValidationRequest)
+                .set_validation_request_id(&new_request.id)
+                .set_validation_status(new_request.status.to_string()),
+        )
+        .set_data(to_binary(&new_request)?)
+        .to_ok()
+}
+
+/// Checks that a submission contains at most one verdict for each scope of a validation request
+/// which is outstanding for the submitting validator, and no verdicts for any other scopes.
+///
+/// # Parameters
+///
+/// * `request` The validation request which the results pertain to.
+/// * `validator` The bech32 address of the validator submitting the results.
+/// * `submission` The submitted validation results.
+fn validate_scope_results(
+    request: &ValidationRequestOrder,
+    validator: &Addr,
+    submission: &ValidationResultSubmission,
+) -> ContractResult<()> {
+    let mut errors = vec![];
+    if submission.get_scope_results().is_empty() {
+        errors.push("at least one scope result must be submitted".to_string());
+    }
+    let outstanding_scopes = request.get_outstanding_scopes(Some(validator));
+    let mut submitted_scopes = HashSet::new();
+    for scope_result in submission.get_scope_results() {
+        if !request.get_scopes().contains(&scope_result.scope) {
+            errors.push(format!(
+                "scope [{}] is not part of the validation request",
+                scope_result.scope
+            ));
+        } else if !outstanding_scopes.contains(&scope_result.scope) {
+            errors.push(format!(
+                "scope [{}] already has a submitted result",
+                scope_result.scope
+            ));
+        } else if !submitted_scopes.insert(&scope_result.scope) {
+            errors.push(format!(
+                "scope [{}] was given more than one result",
+                scope_result.scope
+            ));
+        }
+    }
+    errors.extend(validate_access_routes(
+        submission.maybe_get_access_routes().unwrap_or_default(),
+    ));
+    if !errors.is_empty() {
+        ContractError::InvalidRequest {
+            message: errors.join(", "),
+        }
+        .to_err()
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        dispute_validation_result, resolve_dispute, settle_validation_request,
+        submit_validation_results,
+    };
+    use crate::{
+        execute::validation_request::accept_request_for_validation,
+        query::request::query_outstanding_scopes,
+        storage::{
+            request::get_request,
+            validation_definition::{get_validation_definition, update_validation_definition},
+            validation_result::may_get_validation_result,
+        },
+        test::{
+            arbitrary::{arb_addr, arb_entity, arb_validation_request, arb_validation_verdict},
+            helpers::{
+                set_up_claim, set_up_contract, set_up_request, set_up_validator_configuration,
+                single_attribute_for_key, MockOwnedDeps, TEST_ADMIN, TEST_BIND_NAME,
+            },
+        },
+        types::{
+            access_definition::{AccessDefinition, AccessDefinitionType},
+            access_route::AccessRoute,
+            core::error::ContractError,
+            expiration::Duration,
+            request::{
+                validation_request::{DisputeResolution, ValidationRequestStatus},
+                validation_result::ValidationResultSubmission,
+            },
+            validation_cost::ValidationCost,
+            validation_result::{
+                ScopeValidationAttribute, ScopeValidationResult, ValidationVerdict,
+            },
+        },
+        util::{
+            constants::{EVENT_TYPE_KEY, RESULTS_SCOPE_ADDRESS_KEY, VALIDATION_STATUS_KEY},
+            escrow::{generate_payment_msg, subtract_coins},
+            functions::generate_validation_definition_attribute_name,
+        },
+    };
+
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, from_binary, Addr, BankMsg, CosmosMsg, Decimal, Uint128};
+    use proptest::collection::vec;
+    use proptest::sample::size_range;
+    use proptest::{prop_assert, prop_assert_eq, prop_assume, proptest};
+
+    use provwasm_std::add_json_attribute;
+
+    proptest! {
+        #[test]
+        fn submit_results_for_accepted_request(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+            verdict in arb_validation_verdict(),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            let mut scopes = request.scopes.clone();
+            scopes.sort();
+            scopes.dedup();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            let submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address: results_scope_address.clone(),
+                scope_results: scopes.iter().map(|scope| ScopeValidationResult { scope: scope.clone(), verdict: verdict.clone() }).collect(),
+                findings: Some("all good".to_string()),
+                access_routes: None,
+            };
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission);
+            prop_assert!(response.is_ok(), "submitting validation results produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("submit_validation_results", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            prop_assert_eq!(results_scope_address.as_str(), single_attribute_for_key(&response, RESULTS_SCOPE_ADDRESS_KEY));
+            prop_assert_eq!("fulfilled", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            prop_assert_eq!(
+                generate_payment_msg(&validator, &quote).into_iter().collect::<Vec<_>>(),
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+
+            prop_assert_eq!(ValidationRequestStatus::Fulfilled, get_request(&deps.storage, &id).unwrap().status);
+            prop_assert!(may_get_validation_result(&deps.storage, &id, &validator).is_some());
+        }
+
+        #[test]
+        fn submit_results_pays_validation_costs(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            destination in arb_entity(None),
+            verdict in arb_validation_verdict(),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![
+                ValidationCost { amount: Uint128::new(4), denom: "d".to_string(), destination: destination.clone() },
+            ]);
+            let mut request = request;
+            request.quote.push(coin(10, "d"));
+            request.scopes.sort();
+            request.scopes.dedup();
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            let submission = ValidationResultSubmission {
+                request_id: id,
+                results_scope_address: owner.clone(),
+                scope_results: scopes.into_iter().map(|scope| ScopeValidationResult { scope, verdict: verdict.clone() }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission);
+            prop_assert!(response.is_ok(), "submitting validation results produced an error: {}", response.unwrap_err());
+            let mut expected_msgs = vec![CosmosMsg::Bank(BankMsg::Send { to_address: destination.address.to_string(), amount: vec![coin(4, "d")] })];
+            expected_msgs.extend(generate_payment_msg(&validator, &subtract_coins(&quote, &[coin(4, "d")])));
+            prop_assert_eq!(
+                expected_msgs,
+                response.unwrap().messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn submit_results_writes_scope_attributes(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+            verdict in arb_validation_verdict(),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let mut definition = get_validation_definition(&deps.storage, request.validation_type.to_lowercase()).unwrap();
+            let bound_name = generate_validation_definition_attribute_name(&request.validation_type, TEST_BIND_NAME);
+            definition.bound_name = Some(bound_name.clone());
+            update_validation_definition(deps.as_mut().storage, &definition).unwrap();
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let mut request = request;
+            request.scopes.sort();
+            request.scopes.dedup();
+            prop_assume!(request.scopes.iter().all(|scope| !scope.as_str().trim().is_empty()));
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            let submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address: results_scope_address.clone(),
+                scope_results: scopes.iter().map(|scope| ScopeValidationResult { scope: scope.clone(), verdict: verdict.clone() }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission);
+            prop_assert!(response.is_ok(), "submitting validation results produced an error: {}", response.unwrap_err());
+            let mut expected_msgs = scopes
+                .iter()
+                .map(|scope| add_json_attribute(scope.clone(), &bound_name, &ScopeValidationAttribute {
+                    request_id: id.clone(),
+                    validator: validator.clone(),
+                    results_scope_address: results_scope_address.clone(),
+                    verdict: verdict.clone(),
+                }).unwrap())
+                .collect::<Vec<_>>();
+            expected_msgs.extend(generate_payment_msg(&validator, &quote));
+            prop_assert_eq!(
+                expected_msgs,
+                response.unwrap().messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn submit_results_attaches_validator_access_routes(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+            verdict in arb_validation_verdict(),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let mut request = request;
+            request.scopes.sort();
+            request.scopes.dedup();
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+            let access_route = AccessRoute { route: "https://validator.example.com".to_string(), name: None };
+            let mut submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address,
+                scope_results: scopes.into_iter().map(|scope| ScopeValidationResult { scope, verdict: verdict.clone() }).collect(),
+                findings: None,
+                access_routes: Some(vec![AccessRoute { route: "".to_string(), name: Some("blank".to_string()) }]),
+            };
+            match submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission.clone()) {
+                Err(ContractError::InvalidRequest { .. }) => {},
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+
+            submission.access_routes = Some(vec![access_route.clone()]);
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission);
+            prop_assert!(response.is_ok(), "submitting validation results with access routes produced an error: {}", response.unwrap_err());
+            prop_assert_eq!(
+                vec![AccessDefinition {
+                    owner_address: validator.to_string(),
+                    access_routes: vec![access_route],
+                    definition_type: AccessDefinitionType::Verifier,
+                }],
+                get_request(&deps.storage, &id).unwrap().access_definitions
+            );
+        }
+
+        #[test]
+        fn submit_results_from_other_validator(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            other_validator in arb_addr(),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+            verdict in arb_validation_verdict(),
+        ) {
+            prop_assume!(validator != other_validator);
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            let submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address,
+                scope_results: scopes.into_iter().map(|scope| ScopeValidationResult { scope, verdict: verdict.clone() }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(other_validator.as_str(), &[]), submission);
+            match response {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            prop_assert_eq!(ValidationRequestStatus::Pending, get_request(&deps.storage, &id).unwrap().status);
+        }
+
+        #[test]
+        fn submit_results_incrementally(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+            verdict in arb_validation_verdict(),
+        ) {
+            let mut request = request;
+            request.scopes.sort();
+            request.scopes.dedup();
+            prop_assume!(request.scopes.len() > 1);
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+            let submission_for = |scopes: &[Addr]| ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address: results_scope_address.clone(),
+                scope_results: scopes.iter().map(|scope| ScopeValidationResult { scope: scope.clone(), verdict: verdict.clone() }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            let outstanding_scopes = |deps: &MockOwnedDeps| -> Option<Vec<Addr>> {
+                from_binary(&query_outstanding_scopes(&deps.storage, id.clone(), None).unwrap()).unwrap()
+            };
+            prop_assert_eq!(Some(scopes.clone()), outstanding_scopes(&deps));
+
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission_for(&scopes[..1]));
+            prop_assert!(response.is_ok(), "submitting partial validation results produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("pending", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            prop_assert!(response.messages.is_empty());
+            prop_assert_eq!(Some(scopes[1..].to_vec()), outstanding_scopes(&deps));
+            match submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission_for(&scopes[..1])) {
+                Err(ContractError::InvalidRequest { .. }) => {},
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission_for(&scopes[1..]));
+            prop_assert!(response.is_ok(), "submitting the remaining validation results produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("fulfilled", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            prop_assert_eq!(
+                generate_payment_msg(&validator, &quote).into_iter().collect::<Vec<_>>(),
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(Some(vec![]), outstanding_scopes(&deps));
+            let result = may_get_validation_result(&deps.storage, &id, &validator).unwrap();
+            prop_assert_eq!(scopes, result.get_scope_results().iter().map(|scope_result| scope_result.scope.clone()).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn submit_results_requires_agreeing_validators(
+            owner in arb_addr(),
+            validators in vec(arb_addr(), size_range(3..=3)),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+        ) {
+            let mut validators = validators;
+            validators.sort();
+            validators.dedup();
+            prop_assume!(validators.len() == 3);
+            let mut request = request;
+            request.allowed_validators = Some(validators.clone());
+            request.required_validator_count = Some(2);
+            request.quote = vec![coin(11, "d")];
+            let mut deps = set_up_contract(&request.validation_type);
+            for validator in &validators {
+                set_up_validator_configuration(&mut deps, validator, &request.validation_type, vec![]);
+            }
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            set_up_request(&mut deps, &owner, request);
+            let accept = |deps: &mut MockOwnedDeps, validator: &Addr| {
+                accept_request_for_validation(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone())
+            };
+            let submit = |deps: &mut MockOwnedDeps, validator: &Addr, verdict: ValidationVerdict| {
+                let submission = ValidationResultSubmission {
+                    request_id: id.clone(),
+                    results_scope_address: results_scope_address.clone(),
+                    scope_results: scopes.iter().map(|scope| ScopeValidationResult { scope: scope.clone(), verdict: verdict.clone() }).collect(),
+                    findings: None,
+                    access_routes: None,
+                };
+                submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission)
+            };
+
+            accept(&mut deps, &validators[0]).expect("the first of two required validators should be able to accept the request");
+            prop_assert_eq!(ValidationRequestStatus::Requested, get_request(&deps.storage, &id).unwrap().status);
+            accept(&mut deps, &validators[1]).expect("the second of two required validators should be able to accept the request");
+            prop_assert_eq!(ValidationRequestStatus::Pending, get_request(&deps.storage, &id).unwrap().status);
+            prop_assert!(accept(&mut deps, &validators[2]).is_err(), "a third validator unexpectedly accepted a fully claimed request");
+
+            let response = submit(&mut deps, &validators[0], ValidationVerdict::Pass)
+                .expect("submitting complete results from the first validator should succeed");
+            prop_assert!(response.messages.is_empty());
+            let response = submit(&mut deps, &validators[1], ValidationVerdict::Fail)
+                .expect("submitting complete results from the second validator should succeed");
+            prop_assert!(response.messages.is_empty());
+            prop_assert_eq!("requested", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+
+            accept(&mut deps, &validators[2]).expect("a third validator should be able to accept a request without agreeing results");
+            let response = submit(&mut deps, &validators[2], ValidationVerdict::Pass)
+                .expect("submitting agreeing results from the third validator should succeed");
+            prop_assert_eq!("fulfilled", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            let mut expected_msgs = vec![];
+            expected_msgs.extend(generate_payment_msg(&validators[0], &[coin(5, "d")]));
+            expected_msgs.extend(generate_payment_msg(&validators[2], &[coin(5, "d")]));
+            expected_msgs.extend(generate_payment_msg(&owner, &[coin(1, "d")]));
+            prop_assert_eq!(
+                expected_msgs,
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            prop_assert_eq!(ValidationRequestStatus::Fulfilled, get_request(&deps.storage, &id).unwrap().status);
+        }
+
+        #[test]
+        fn disputed_results_are_held_until_resolved(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+            verdict in arb_validation_verdict(),
+        ) {
+            prop_assume!(owner != validator && owner.as_str() != TEST_ADMIN);
+            let mut deps = set_up_contract(&request.validation_type);
+            let mut definition = get_validation_definition(&deps.storage, request.validation_type.to_lowercase()).unwrap();
+            definition.dispute_period = Some(Duration::Height(5));
+            update_validation_definition(deps.as_mut().storage, &definition).unwrap();
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let mut request = request;
+            request.quote = vec![coin(10, "d")];
+            request.scopes.sort();
+            request.scopes.dedup();
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+            let submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address,
+                scope_results: scopes.into_iter().map(|scope| ScopeValidationResult { scope, verdict: verdict.clone() }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            let response = submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission)
+                .expect("submitting complete validation results should succeed");
+            prop_assert_eq!("fulfilled", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            prop_assert!(response.messages.is_empty(), "payment was not held for the dispute period");
+            prop_assert!(
+                settle_validation_request(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone()).is_err(),
+                "settling a validation request during its dispute period unexpectedly succeeded",
+            );
+
+            match dispute_validation_result(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), id.clone(), "wrong".to_string()) {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            let response = dispute_validation_result(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), id.clone(), "wrong".to_string());
+            prop_assert!(response.is_ok(), "disputing validation results produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("dispute_validation_result", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            prop_assert_eq!("disputed", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            let mut env = mock_env();
+            env.block.height += 10;
+            prop_assert!(
+                settle_validation_request(deps.as_mut(), env, mock_info(validator.as_str(), &[]), id.clone()).is_err(),
+                "settling a disputed validation request unexpectedly succeeded",
+            );
+
+            let resolution = DisputeResolution::Split { owner_fraction: Decimal::percent(30) };
+            match resolve_dispute(deps.as_mut(), mock_env(), mock_info(owner.as_str(), &[]), id.clone(), resolution.clone()) {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            let response = resolve_dispute(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), id.clone(), resolution.clone());
+            prop_assert!(response.is_ok(), "resolving a dispute produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("fulfilled", single_attribute_for_key(&response, VALIDATION_STATUS_KEY));
+            let mut expected_msgs = vec![];
+            expected_msgs.extend(generate_payment_msg(&validator, &[coin(7, "d")]));
+            expected_msgs.extend(generate_payment_msg(&owner, &[coin(3, "d")]));
+            prop_assert_eq!(
+                expected_msgs,
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            let resolved_request = get_request(&deps.storage, &id).unwrap();
+            prop_assert_eq!(Some(&resolution), resolved_request.maybe_get_dispute().and_then(|dispute| dispute.maybe_get_resolution()));
+            prop_assert!(!resolved_request.holds_escrow());
+            prop_assert!(
+                resolve_dispute(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), id, resolution).is_err(),
+                "resolving an already resolved dispute unexpectedly succeeded",
+            );
+        }
+
+        #[test]
+        fn undisputed_results_are_settled_after_dispute_period(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            results_scope_address in arb_addr(),
+            verdict in arb_validation_verdict(),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            let mut definition = get_validation_definition(&deps.storage, request.validation_type.to_lowercase()).unwrap();
+            definition.dispute_period = Some(Duration::Height(5));
+            update_validation_definition(deps.as_mut().storage, &definition).unwrap();
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let mut request = request;
+            request.scopes.sort();
+            request.scopes.dedup();
+            let id = request.id.clone();
+            let scopes = request.scopes.clone();
+            let quote = request.quote.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+            let submission = ValidationResultSubmission {
+                request_id: id.clone(),
+                results_scope_address,
+                scope_results: scopes.into_iter().map(|scope| ScopeValidationResult { scope, verdict: verdict.clone() }).collect(),
+                findings: None,
+                access_routes: None,
+            };
+            submit_validation_results(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), submission)
+                .expect("submitting complete validation results should succeed");
+
+            let mut env = mock_env();
+            env.block.height += 10;
+            prop_assert!(
+                dispute_validation_result(deps.as_mut(), env.clone(), mock_info(owner.as_str(), &[]), id.clone(), "late".to_string()).is_err(),
+                "disputing validation results after the dispute period unexpectedly succeeded",
+            );
+            let response = settle_validation_request(deps.as_mut(), env.clone(), mock_info(TEST_ADMIN, &[]), id.clone());
+            prop_assert!(response.is_ok(), "settling a validation request produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("settle_validation_request", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            prop_assert_eq!(
+                generate_payment_msg(&validator, &quote).into_iter().collect::<Vec<_>>(),
+                response.messages.into_iter().map(|msg| msg.msg).collect::<Vec<_>>()
+            );
+            prop_assert!(
+                settle_validation_request(deps.as_mut(), env, mock_info(TEST_ADMIN, &[]), id).is_err(),
+                "settling an already settled validation request unexpectedly succeeded",
+            );
+        }
+    }
+}
@@ -2,13 +2,16 @@ use crate::{
     storage::{
         contract_info::get_contract_info,
         entity::get_entity,
+        request::{get_pending_requests_claimed_by, store_request},
+        validation_result::{delete_validation_result, get_agreeing_validation_results},
         validator_configuration::{
-            get_validator_configuration, insert_validator_configuration,
-            store_validator_configuration,
+            delete_validator_configuration_by_key, get_validator_configuration,
+            insert_validator_configuration, store_validator_configuration,
         },
     },
     types::{
         core::error::ContractError,
+        fee::FeeOperation,
         request::validator_configuration::{
             ValidatorConfigurationCreationRequest, ValidatorConfigurationUpdateRequest,
         },
@@ -16,17 +19,18 @@ use crate::{
     },
     util::{
         aliases::{DepsMutC, EntryPointResponse},
-        event_attributes::{EventAttributes, EventType},
+        event_attributes::{EventAdditionalMetadata, EventAttributes, EventType},
+        fees::{add_contract_fee_msg, generate_contract_fee_msg},
         helpers::{check_funds_are_empty, get_validator_configuration_update},
     },
 };
 
-use cosmwasm_std::{Env, MessageInfo, Response};
+use cosmwasm_std::{to_binary, Addr, Env, MessageInfo, Response};
 use result_extensions::ResultExtensions;
 
 pub fn create_new_validator_configuration(
     deps: DepsMutC,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     request: ValidatorConfigurationCreationRequest,
 ) -> EntryPointResponse {
@@ -54,14 +58,21 @@ pub fn create_new_validator_configuration(
     }
     // Store the validator configuration
     insert_validator_configuration(deps.storage, &request.clone().into())?;
+    let fee_msg = generate_contract_fee_msg(
+        FeeOperation::CreateValidatorConfiguration,
+        &deps.as_ref(),
+        env.contract.address,
+    )?;
     // Construct the response
-    Response::new()
-        .add_attributes(
+    add_contract_fee_msg(
+        Response::new().add_attributes(
             EventAttributes::new(EventType::AddValidatorConfiguration)
                 .set_validation_type(&request.validation_type)
                 .set_validator(&request.validator),
-        )
-        .to_ok()
+        ),
+        FeeOperation::CreateValidatorConfiguration,
+        fee_msg,
+    )
 }
 
 pub fn update_existing_validator_configuration(
@@ -118,3 +129,160 @@ pub fn update_existing_validator_configuration(
         )
         .to_ok()
 }
+
+pub fn delete_validator_configuration(
+    deps: DepsMutC,
+    _env: Env,
+    info: MessageInfo,
+    validator: Addr,
+    validation_type: String,
+    force: Option<bool>,
+) -> EntryPointResponse {
+    // Validate the request
+    check_funds_are_empty(&info)?;
+    let state = get_contract_info(deps.storage)?;
+    if info.sender != validator && info.sender != state.admin {
+        return ContractError::Unauthorized {
+            reason: "must be the contract admin to delete a validator configuration for a different address"
+                .to_string(),
+        }
+        .to_err();
+    }
+    let pending_requests =
+        get_pending_requests_claimed_by(deps.storage, &validator, &validation_type)?;
+    if !pending_requests.is_empty() && !force.unwrap_or(false) {
+        return ContractError::InvalidRequest {
+            message: format!(
+                "validator [{}] has pending validation requests of validation type [{}] with ids [{}], which must be completed or released before its configuration can be deleted",
+                validator,
+                validation_type,
+                pending_requests
+                    .iter()
+                    .map(|request| request.get_id())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+        .to_err();
+    }
+    // Delete the validator configuration
+    let deleted_configuration = delete_validator_configuration_by_key(
+        deps.storage,
+        ValidatorConfiguration::generate_storage_key(&validator, &validation_type),
+    )?;
+    // Return any requests claimed by the validator to the marketplace, discarding any results submitted so far
+    let mut metadata = EventAdditionalMetadata::new();
+    if !pending_requests.is_empty() {
+        for old_request in &pending_requests {
+            let mut new_request = old_request.clone();
+            new_request.release(
+                &validator,
+                format!(
+                    "the validator configuration for validation type [{}] was deleted",
+                    validation_type
+                ),
+            );
+            new_request.refresh_status(
+                get_agreeing_validation_results(deps.storage, &new_request).len() as u32,
+            );
+            store_request(deps.storage, &new_request, Some(old_request))?;
+            delete_validation_result(deps.storage, old_request.get_id(), &validator)?;
+        }
+        metadata.add_metadata(
+            "released_request_ids",
+            pending_requests
+                .iter()
+                .map(|request| request.get_id())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+    // Construct the response
+    Response::new()
+        .add_attributes(
+            EventAttributes::new(EventType::DeleteValidatorConfiguration)
+                .set_validation_type(&validation_type)
+                .set_validator(&validator)
+                .set_additional_metadata(&metadata),
+        )
+        .set_data(to_binary(&deleted_configuration)?)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::delete_validator_configuration;
+    use crate::{
+        storage::{request::get_request, validator_configuration::may_get_validator_configuration},
+        test::{
+            arbitrary::{arb_addr, arb_validation_request},
+            helpers::{
+                set_up_claim, set_up_contract, set_up_contract_info, set_up_request,
+                set_up_validator_configuration, single_attribute_for_key, TEST_ADMIN,
+            },
+        },
+        types::{
+            core::error::ContractError, request::validation_request::ValidationRequestStatus,
+            validator_configuration::ValidatorConfiguration,
+        },
+        util::constants::{ADDITIONAL_METADATA_KEY, EVENT_TYPE_KEY},
+    };
+
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use proptest::{prop_assert, prop_assert_eq, prop_assume, proptest};
+    use provwasm_mocks::mock_dependencies;
+
+    proptest! {
+        #[test]
+        fn delete_validator_configuration_with_pending_request(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validator_configuration(&mut deps, &validator, &request.validation_type, vec![]);
+            let id = request.id.clone();
+            let validation_type = request.validation_type.clone();
+            let storage_key = ValidatorConfiguration::generate_storage_key(&validator, &validation_type);
+            set_up_request(&mut deps, &owner, request);
+            set_up_claim(&mut deps, &validator, &id);
+
+            let response = delete_validator_configuration(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), validator.clone(), validation_type.clone(), None);
+            match response {
+                Err(ContractError::InvalidRequest { message }) => prop_assert!(message.contains(&id)),
+                other => prop_assert!(false, "expected an invalid request error but got: {:?}", other),
+            }
+            prop_assert!(may_get_validator_configuration(&deps.storage, &storage_key).is_some());
+
+            let response = delete_validator_configuration(deps.as_mut(), mock_env(), mock_info(validator.as_str(), &[]), validator.clone(), validation_type, Some(true));
+            prop_assert!(response.is_ok(), "force deleting a validator configuration produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!("delete_validator_configuration", single_attribute_for_key(&response, EVENT_TYPE_KEY));
+            prop_assert!(single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY).contains(&id));
+            prop_assert!(may_get_validator_configuration(&deps.storage, &storage_key).is_none());
+            let released_request = get_request(&deps.storage, &id).unwrap();
+            prop_assert_eq!(&ValidationRequestStatus::Requested, &released_request.status);
+            prop_assert!(released_request.get_claims().is_empty());
+        }
+
+        #[test]
+        fn delete_validator_configuration_of_other_validator(
+            validator in arb_addr(),
+            other_address in arb_addr(),
+            validation_type in r"\S+",
+        ) {
+            prop_assume!(validator != other_address && other_address.as_str() != TEST_ADMIN);
+            let mut deps = mock_dependencies(&[]);
+            set_up_contract_info(&mut deps);
+            set_up_validator_configuration(&mut deps, &validator, &validation_type, vec![]);
+
+            let response = delete_validator_configuration(deps.as_mut(), mock_env(), mock_info(other_address.as_str(), &[]), validator.clone(), validation_type.clone(), Some(true));
+            match response {
+                Err(ContractError::Unauthorized { .. }) => {},
+                other => prop_assert!(false, "expected an unauthorized error but got: {:?}", other),
+            }
+            let response = delete_validator_configuration(deps.as_mut(), mock_env(), mock_info(TEST_ADMIN, &[]), validator, validation_type, None);
+            prop_assert!(response.is_ok(), "deleting a validator configuration as the admin produced an error: {}", response.unwrap_err());
+        }
+    }
+}
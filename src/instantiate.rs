@@ -3,9 +3,10 @@ use crate::types::core::error::ContractError;
 use crate::types::core::msg::InstantiateMsg;
 use crate::util::aliases::{ContractResult, DepsMutC, EntryPointResponse};
 use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::fees::validate_fee_schedule;
 use crate::util::helpers::check_funds_are_empty;
 
-use cosmwasm_std::{Env, MessageInfo, Response};
+use cosmwasm_std::{Api, Env, MessageInfo, Response};
 use provwasm_std::{bind_name, NameBinding};
 use result_extensions::ResultExtensions;
 
@@ -15,13 +16,13 @@ use result_extensions::ResultExtensions;
 /// # Parameters
 ///
 /// * `deps` A mutable dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like the contract's internal storage and a querier to retrieve blockchain objects.
+///   resources like the contract's internal storage and a querier to retrieve blockchain objects.
 /// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.
+///   details, as well as blockchain information at the time of the transaction.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` A custom instantiation message defined by this contract for creating the initial
-/// configuration used by the contract.
+///   configuration used by the contract.
 pub fn instantiate_contract(
     deps: DepsMutC,
     env: Env,
@@ -29,12 +30,12 @@ pub fn instantiate_contract(
     msg: InstantiateMsg,
 ) -> EntryPointResponse {
     check_funds_are_empty(&info)?;
-    validate_instantiate_msg(&msg)?;
+    validate_instantiate_msg(deps.api, &msg)?;
     let contract_info = ContractInfo::new(
         info.sender,
         msg.bind_name,
         msg.contract_name,
-        Some(msg.create_request_nhash_fee),
+        msg.fee_schedule,
     );
     set_contract_info(deps.storage, &contract_info)?;
 
@@ -56,9 +57,10 @@ pub fn instantiate_contract(
 ///
 /// # Parameters
 ///
+/// * `api` The cosmwasm api, used to validate the recipients of the fees in the fee schedule.
 /// * `msg` The custom instantiation message defined by this contract for creating the initial
-/// configuration used by the contract.
-fn validate_instantiate_msg(msg: &InstantiateMsg) -> ContractResult<()> {
+///   configuration used by the contract.
+fn validate_instantiate_msg(api: &dyn Api, msg: &InstantiateMsg) -> ContractResult<()> {
     let mut errors = vec![];
     if msg.bind_name.trim().is_empty() {
         errors.push("bind_name value was empty".to_string());
@@ -66,6 +68,7 @@ fn validate_instantiate_msg(msg: &InstantiateMsg) -> ContractResult<()> {
     if msg.contract_name.trim().is_empty() {
         errors.push("contract_name value was empty".to_string());
     }
+    errors.append(&mut validate_fee_schedule(api, &msg.fee_schedule));
     if !errors.is_empty() {
         ContractError::InvalidInstantiation {
             message: errors.join(", "),
@@ -1,34 +1,152 @@
 use crate::storage::contract_info::{
-    get_contract_info, set_contract_info, ContractInfo, CONTRACT_TYPE, CONTRACT_VERSION,
+    get_contract_info, move_legacy_contract_info, set_contract_info, ContractInfo, CONTRACT_TYPE,
+    CONTRACT_VERSION,
 };
+use crate::storage::request::migrate_legacy_requests;
+use crate::storage::validation_definition::backfill_bound_names;
 use crate::types::core::error::ContractError;
-use crate::util::aliases::{DepsMutC, EntryPointResponse};
+use crate::types::migration::{MigrationReport, MigrationStepReport};
+use crate::types::request::settings_update::SettingsUpdate;
+use crate::util::aliases::{ContractResult, DepsMutC, EntryPointResponse};
+use crate::util::constants::LEGACY_VALIDATION_TYPE;
 use crate::util::event_attributes::{EventAttributes, EventType};
+use crate::util::helpers::{
+    apply_settings_update, get_contract_state_update, rebind_contract_names,
+};
 
-use cosmwasm_std::{to_binary, Response};
+use cosmwasm_std::{to_binary, Env, Response, Storage};
 use result_extensions::ResultExtensions;
 use semver::Version;
 
-// TODO: Consider how operations like binding & unbinding names must be made resilient to migrations
-// TODO: The below is likely an inaccurate and incomplete implementation of migrations.
+/// A single step of a storage migration, which rewrites the contract's stored data into the
+/// format introduced by a particular contract version.
+pub struct MigrationStep {
+    /// The contract version which introduced the storage format produced by the step. The step is run
+    /// when migrating from a stored version lower than this one to a version at least as high as it.
+    pub version: &'static str,
+    /// A description of the purpose of the step.
+    pub description: &'static str,
+    /// Applies the step to the contract's storage, returning descriptions of the changes made.
+    pub apply: fn(&mut dyn Storage) -> ContractResult<Vec<String>>,
+}
+
+/// Every migration step of the contract, in ascending order of version.
+pub const MIGRATION_STEPS: &[MigrationStep] = &[MigrationStep {
+    version: "0.2.0",
+    description: "convert legacy validation requests to their current format, giving each the legacy validation type and clearing its unescrowed quote, and rebuild their indices, then record the names bound for legacy validation definitions and index them by whether they are enabled",
+    apply: |storage| {
+        let request_count = migrate_legacy_requests(storage)?;
+        let bind_name = get_contract_info(storage)?.bind_name;
+        let definition_count = backfill_bound_names(storage, &bind_name)?;
+        Ok(vec![
+            format!(
+                "converted [{}] legacy validation requests to validation type [{}]",
+                request_count, LEGACY_VALIDATION_TYPE
+            ),
+            format!(
+                "recorded the bound names of [{}] legacy validation definitions under [{}]",
+                definition_count, bind_name
+            ),
+        ])
+    },
+}];
+
 /// The main entrypoint function for running a code migration.  Referred to in the [contract file](crate::contract).
 ///
 /// # Parameters
 ///
 /// * `deps` A mutable dependencies object provided by cosmwasm in the migrate entrypoint.
-pub fn migrate_contract(deps: DepsMutC) -> EntryPointResponse {
-    let mut contract_info = get_contract_info(deps.storage)?;
-    check_valid_migration_target(&contract_info)?;
-    contract_info.contract_version = CONTRACT_VERSION.to_string();
+/// * `env` An environment object provided by cosmwasm in the migrate entrypoint.
+/// * `overrides` Optional changes to the contract's settings to apply after migrating its storage.
+pub fn migrate_contract(deps: DepsMutC, env: Env, overrides: SettingsUpdate) -> EntryPointResponse {
+    let report = run_migration(deps.storage)?;
+    let migrated_contract_info = get_contract_info(deps.storage)?;
+    let contract_info = apply_settings_update(deps.api, &migrated_contract_info, &overrides)
+        .map_err(|err| match err {
+            ContractError::InvalidRequest { message } => {
+                ContractError::InvalidMigration { message }
+            }
+            err => err,
+        })?;
+    // Move the contract's name bindings if its bind name was overridden
+    let messages = if contract_info.bind_name != migrated_contract_info.bind_name {
+        rebind_contract_names(
+            deps.storage,
+            &env.contract.address,
+            &migrated_contract_info.bind_name,
+            &contract_info.bind_name,
+        )?
+    } else {
+        vec![]
+    };
     set_contract_info(deps.storage, &contract_info)?;
+    let old_contract_info = ContractInfo {
+        contract_version: report.from_version.clone(),
+        ..migrated_contract_info
+    };
+    let mut additional_metadata = get_contract_state_update(&old_contract_info, &contract_info);
+    if let Some(legacy_namespace) = &report.legacy_contract_info_namespace {
+        additional_metadata.add_metadata("legacy_contract_info_namespace", legacy_namespace);
+    }
+    if !report.steps.is_empty() {
+        additional_metadata.add_metadata(
+            "migration_steps",
+            report
+                .steps
+                .iter()
+                .map(|step| step.version.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
     Response::new()
+        .add_messages(messages)
         .add_attributes(
-            EventAttributes::new(EventType::MigrateContract).set_contract_info(&contract_info),
+            EventAttributes::new(EventType::MigrateContract)
+                .set_contract_info(&contract_info)
+                .set_additional_metadata(&additional_metadata),
         )
         .set_data(to_binary(&contract_info)?)
         .to_ok()
 }
 
+/// Migrates the contract's storage to the current contract version by recovering any contract info
+/// stored under a legacy namespace, running each pending [migration step](MIGRATION_STEPS) in order,
+/// and recording the new contract version. Returns a report of the changes made.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn run_migration(storage: &mut dyn Storage) -> ContractResult<MigrationReport> {
+    let legacy_contract_info_namespace = move_legacy_contract_info(storage)?;
+    let mut contract_info = get_contract_info(storage)?;
+    check_valid_migration_target(&contract_info)?;
+    let from_version = contract_info.contract_version.parse::<Version>()?;
+    let to_version = CONTRACT_VERSION.parse::<Version>()?;
+    let mut steps = vec![];
+    for step in MIGRATION_STEPS {
+        let step_version = step.version.parse::<Version>()?;
+        if step_version > from_version && step_version <= to_version {
+            steps.push(MigrationStepReport {
+                version: step.version.to_string(),
+                description: step.description.to_string(),
+                changes: (step.apply)(storage)?,
+            });
+        }
+    }
+    contract_info.contract_version = CONTRACT_VERSION.to_string();
+    set_contract_info(storage, &contract_info)?;
+    MigrationReport {
+        from_version: from_version.to_string(),
+        to_version: to_version.to_string(),
+        legacy_contract_info_namespace,
+        steps,
+        changed_key_count: None,
+        removed_key_count: None,
+    }
+    .to_ok()
+}
+
 /// Verifies that the migration is going to a proper version and the contract name of the new wasm matches
 /// the value in the Cargo.toml.
 ///
@@ -62,4 +180,316 @@ fn check_valid_migration_target(contract_info: &ContractInfo) -> Result<(), Cont
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::{migrate_contract, MIGRATION_STEPS};
+    use crate::{
+        execute::validation_request::{
+            cancel_request_for_validation, delete_request_for_validation,
+        },
+        query::migration::query_migration_dry_run,
+        storage::{
+            contract_info::{
+                get_contract_info, set_contract_info, LegacyContractInfo, CONTRACT_VERSION,
+            },
+            request::{get_request, get_requests_by_owner, get_requests_by_validation_type},
+            validation_definition::{get_validation_definition, get_validation_definitions},
+        },
+        test::{
+            arbitrary::{arb_contract_info, arb_fee_schedule},
+            helpers::{set_up_contract_info, single_attribute_for_key, TEST_ADMIN, TEST_BIND_NAME},
+        },
+        types::{
+            fee::{ContractFee, FeeDenom, FeeOperation},
+            migration::MigrationReport,
+            request::{
+                settings_update::SettingsUpdate,
+                validation_request::{ValidationRequestOrder, ValidationRequestStatus},
+            },
+        },
+        util::constants::{ADDITIONAL_METADATA_KEY, LEGACY_VALIDATION_TYPE},
+    };
+
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{from_binary, to_vec, Addr, Storage, Uint128};
+    use cw_storage_plus::Map;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::{bind_name, unbind_name, NameBinding};
+    use semver::Version;
+
+    /// Validation requests as stored by version 0.1.0 of the contract, keyed by their IDs.
+    const LEGACY_REQUESTS: [(&str, &str); 2] = [
+        (
+            "legacy-fulfilled-request",
+            r#"{"id":"legacy-fulfilled-request","owner":"requestor","scopes":["scope2"],"allowed_validators":["validator"],"quote":[],"status":"fulfilled"}"#,
+        ),
+        (
+            "legacy-open-request",
+            r#"{"id":"legacy-open-request","owner":"requestor","scopes":["scope1","scope2"],"allowed_validators":null,"quote":[{"denom":"nhash","amount":"100"}],"status":"requested"}"#,
+        ),
+    ];
+
+    /// The [LEGACY_REQUESTS] as they are expected to be stored after a migration.
+    fn expected_legacy_requests() -> Vec<ValidationRequestOrder> {
+        let legacy_request =
+            |id: &str, scopes: &[&str], allowed_validators, status| ValidationRequestOrder {
+                id: id.to_string(),
+                owner: Addr::unchecked("requestor"),
+                validation_type: LEGACY_VALIDATION_TYPE.to_string(),
+                scopes: scopes.iter().map(|scope| Addr::unchecked(*scope)).collect(),
+                allowed_validators,
+                // The contract never collected the quotes of legacy requests, so none is held in escrow
+                quote: vec![],
+                expires_at: None,
+                claim_period: None,
+                required_validator_count: None,
+                status,
+                claims: vec![],
+                last_release: None,
+                settlement: None,
+                dispute: None,
+                access_definitions: vec![],
+            };
+        vec![
+            legacy_request(
+                "legacy-fulfilled-request",
+                &["scope2"],
+                Some(vec![Addr::unchecked("validator")]),
+                ValidationRequestStatus::Fulfilled,
+            ),
+            legacy_request(
+                "legacy-open-request",
+                &["scope1", "scope2"],
+                None,
+                ValidationRequestStatus::Requested,
+            ),
+        ]
+    }
+
+    fn no_overrides() -> SettingsUpdate {
+        SettingsUpdate {
+            new_admin_address: None,
+            new_fee_schedule: None,
+            new_contract_name: None,
+            new_bind_name: None,
+        }
+    }
+
+    #[test]
+    fn migration_steps_are_ordered_and_released() {
+        let versions = MIGRATION_STEPS
+            .iter()
+            .map(|step| step.version.parse::<Version>().unwrap())
+            .collect::<Vec<_>>();
+        let mut sorted_versions = versions.clone();
+        sorted_versions.sort();
+        sorted_versions.dedup();
+        assert_eq!(sorted_versions, versions);
+        assert!(versions
+            .iter()
+            .all(|version| *version <= CONTRACT_VERSION.parse::<Version>().unwrap()));
+    }
+
+    #[test]
+    fn migrated_requests_hold_no_escrow() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.contract_version = "0.1.0".to_string();
+        set_contract_info(deps.as_mut().storage, &contract_info).unwrap();
+        for (id, legacy_json) in LEGACY_REQUESTS {
+            let request_key = Map::<&[u8], ()>::new("request").key(id.as_bytes());
+            deps.storage.set(&request_key, legacy_json.as_bytes());
+        }
+        migrate_contract(deps.as_mut(), mock_env(), no_overrides())
+            .expect("migrating legacy requests should succeed");
+
+        let response = cancel_request_for_validation(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("requestor", &[]),
+            "legacy-open-request".to_string(),
+        )
+        .expect("cancelling a migrated request should succeed");
+        assert!(response.messages.is_empty());
+        for id in ["legacy-open-request", "legacy-fulfilled-request"] {
+            let response = delete_request_for_validation(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(TEST_ADMIN, &[]),
+                id.to_string(),
+                Some(true),
+            )
+            .expect("deleting a migrated request should succeed");
+            assert!(response.messages.is_empty());
+        }
+    }
+
+    #[test]
+    fn migration_records_names_bound_for_legacy_definitions() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.contract_version = "0.1.0".to_string();
+        set_contract_info(deps.as_mut().storage, &contract_info).unwrap();
+        let definition_key = Map::<&[u8], ()>::new("validation_definition").key(b"legacy-type");
+        deps.storage.set(
+            &definition_key,
+            br#"{"validation_type":"legacy-type","display_name":null,"enabled":true}"#,
+        );
+        let overrides = SettingsUpdate {
+            new_bind_name: Some("new-oracle.pb".to_string()),
+            ..no_overrides()
+        };
+        let response = migrate_contract(deps.as_mut(), mock_env(), overrides)
+            .expect("migrating legacy validation definitions should succeed");
+
+        assert_eq!(
+            vec![
+                unbind_name(format!("legacy-type.{}", TEST_BIND_NAME)).unwrap(),
+                unbind_name(TEST_BIND_NAME).unwrap(),
+                bind_name(
+                    "new-oracle.pb",
+                    mock_env().contract.address,
+                    NameBinding::Restricted
+                )
+                .unwrap(),
+                bind_name(
+                    "legacy-type.new-oracle.pb",
+                    mock_env().contract.address,
+                    NameBinding::Restricted
+                )
+                .unwrap(),
+            ],
+            response
+                .messages
+                .iter()
+                .map(|msg| msg.msg.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some("legacy-type.new-oracle.pb"),
+            get_validation_definition(&deps.storage, "legacy-type")
+                .unwrap()
+                .maybe_get_bound_name()
+        );
+        assert_eq!(
+            vec!["legacy-type".to_string()],
+            get_validation_definitions(&deps.storage, Some(true), None, None, None)
+                .items
+                .into_iter()
+                .map(|definition| definition.validation_type)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn migrate_legacy_storage(
+            contract_info in arb_contract_info(true),
+            create_request_nhash_fee in 1..1000u128,
+        ) {
+            let mut deps = mock_dependencies(&[]);
+            let legacy_contract_info = LegacyContractInfo {
+                admin: contract_info.admin,
+                bind_name: contract_info.bind_name,
+                contract_name: contract_info.contract_name,
+                contract_type: contract_info.contract_type,
+                contract_version: "0.1.0".to_string(),
+                create_request_nhash_fee: Uint128::new(create_request_nhash_fee),
+            };
+            deps.storage.set(b"contract_info_0.1.0", &to_vec(&legacy_contract_info).unwrap());
+            for (id, legacy_json) in LEGACY_REQUESTS {
+                let request_key = Map::<&[u8], ()>::new("request").key(id.as_bytes());
+                deps.storage.set(&request_key, legacy_json.as_bytes());
+            }
+            let owner = Addr::unchecked("requestor");
+            prop_assert!(get_contract_info(&deps.storage).is_err());
+            prop_assert!(get_requests_by_owner(&deps.storage, owner.clone(), None, None).items.is_empty());
+
+            let report: MigrationReport = from_binary(&query_migration_dry_run(&deps.storage).unwrap()).unwrap();
+            prop_assert_eq!("0.1.0", report.from_version);
+            prop_assert_eq!(CONTRACT_VERSION, report.to_version);
+            prop_assert_eq!(Some("contract_info_0.1.0".to_string()), report.legacy_contract_info_namespace);
+            prop_assert_eq!(vec!["0.2.0".to_string()], report.steps.iter().map(|step| step.version.clone()).collect::<Vec<_>>());
+            prop_assert!(report.changed_key_count.unwrap() > 0);
+            prop_assert_eq!(Some(1), report.removed_key_count);
+            prop_assert!(get_contract_info(&deps.storage).is_err(), "a dry run of a migration modified storage");
+
+            let response = migrate_contract(deps.as_mut(), mock_env(), no_overrides());
+            prop_assert!(response.is_ok(), "migrating legacy storage produced an error: {}", response.unwrap_err());
+            let contract_info = get_contract_info(&deps.storage).unwrap();
+            prop_assert_eq!(CONTRACT_VERSION, &contract_info.contract_version);
+            prop_assert_eq!(
+                vec![ContractFee {
+                    operation: FeeOperation::CreateRequest,
+                    amount: Uint128::new(create_request_nhash_fee),
+                    denom: FeeDenom::Nhash,
+                    recipient: None,
+                }],
+                contract_info.fee_schedule
+            );
+            prop_assert!(deps.storage.get(b"contract_info_0.1.0").is_none());
+            let expected_requests = expected_legacy_requests();
+            for expected_request in &expected_requests {
+                prop_assert_eq!(expected_request, &get_request(&deps.storage, &expected_request.id).unwrap());
+            }
+            prop_assert_eq!(expected_requests, get_requests_by_owner(&deps.storage, owner, None, None).items);
+            prop_assert_eq!(
+                vec!["legacy-open-request".to_string()],
+                get_requests_by_validation_type(&deps.storage, LEGACY_VALIDATION_TYPE.to_string(), None, None)
+                    .items
+                    .into_iter()
+                    .filter(|request| request.is_open())
+                    .map(|request| request.id)
+                    .collect::<Vec<_>>()
+            );
+            prop_assert!(migrate_contract(deps.as_mut(), mock_env(), no_overrides()).is_err(), "migrating to the stored contract version unexpectedly succeeded");
+        }
+
+        #[test]
+        fn migrate_with_settings_overrides(
+            contract_info in arb_contract_info(true),
+            new_fee_schedule in arb_fee_schedule(),
+        ) {
+            let mut deps = mock_dependencies(&[]);
+            let mut old_contract_info = contract_info;
+            old_contract_info.bind_name = "old-oracle.pb".to_string();
+            old_contract_info.contract_version = "0.1.0".to_string();
+            set_contract_info(deps.as_mut().storage, &old_contract_info).unwrap();
+            let overrides = |new_contract_name: &str| SettingsUpdate {
+                new_admin_address: Some("newadmin".to_string()),
+                new_fee_schedule: Some(new_fee_schedule.clone()),
+                new_contract_name: Some(new_contract_name.to_string()),
+                new_bind_name: Some("new-oracle.pb".to_string()),
+            };
+            prop_assert!(
+                migrate_contract(deps.as_mut(), mock_env(), overrides(" ")).is_err(),
+                "migrating with a blank contract name override unexpectedly succeeded",
+            );
+            // A failed migration is reverted by the chain, which the mock storage does not do
+            set_contract_info(deps.as_mut().storage, &old_contract_info).unwrap();
+
+            let response = migrate_contract(deps.as_mut(), mock_env(), overrides("New Oracle"));
+            prop_assert!(response.is_ok(), "migrating with settings overrides produced an error: {}", response.unwrap_err());
+            let response = response.unwrap();
+            prop_assert_eq!(
+                vec![
+                    unbind_name("old-oracle.pb").unwrap(),
+                    bind_name("new-oracle.pb", mock_env().contract.address, NameBinding::Restricted).unwrap(),
+                ],
+                response.messages.iter().map(|msg| msg.msg.clone()).collect::<Vec<_>>()
+            );
+            let additional_metadata = single_attribute_for_key(&response, ADDITIONAL_METADATA_KEY);
+            prop_assert!(additional_metadata.contains("[new_contract_name=New Oracle]"));
+            prop_assert!(additional_metadata.contains("[old_bind_name=old-oracle.pb]"));
+            let expected_version = format!("[new_contract_version={}]", CONTRACT_VERSION);
+            prop_assert!(additional_metadata.contains(&expected_version));
+            let new_contract_info = get_contract_info(&deps.storage).unwrap();
+            prop_assert_eq!("newadmin", new_contract_info.admin.as_str());
+            prop_assert_eq!(new_fee_schedule, new_contract_info.fee_schedule);
+            prop_assert_eq!("New Oracle", new_contract_info.contract_name);
+            prop_assert_eq!("new-oracle.pb", new_contract_info.bind_name);
+        }
+    }
+}
@@ -6,3 +6,12 @@ use result_extensions::ResultExtensions;
 pub fn query_contract_info(storage: &dyn Storage) -> QueryResult {
     to_binary(&get_contract_info(storage)?)?.to_ok()
 }
+
+/// Returns the [fees](crate::types::fee::ContractFee) charged by the contract for its operations.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+pub fn query_fee_schedule(storage: &dyn Storage) -> QueryResult {
+    to_binary(get_contract_info(storage)?.get_fee_schedule())?.to_ok()
+}
@@ -0,0 +1,21 @@
+use crate::{
+    migrate::run_migration,
+    util::{aliases::QueryResult, storage_overlay::StorageOverlay},
+};
+
+use cosmwasm_std::{to_binary, Storage};
+use result_extensions::ResultExtensions;
+
+/// Simulates a [migration](crate::migrate::migrate_contract) of the contract's storage to the current
+/// contract version without persisting any changes, and returns a report of what it would change.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+pub fn query_migration_dry_run(storage: &dyn Storage) -> QueryResult {
+    let mut overlay = StorageOverlay::new(storage);
+    let mut report = run_migration(&mut overlay)?;
+    report.changed_key_count = Some(overlay.get_changed_key_count());
+    report.removed_key_count = Some(overlay.get_removed_key_count());
+    to_binary(&report)?.to_ok()
+}
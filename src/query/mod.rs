@@ -1,5 +1,7 @@
 pub mod contract_info;
 pub mod entity;
+pub mod migration;
 pub mod request;
-pub mod sort;
 pub mod validation_definition;
+pub mod validation_result;
+pub mod validator_configuration;
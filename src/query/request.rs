@@ -1,6 +1,9 @@
-use super::sort::NO_VALIDATION_REQUEST_SORT;
 use crate::{
-    storage::request::{get_requests_by_owner, get_requests_by_validator, may_get_request},
+    storage::request::{
+        get_open_requests, get_requests_by_owner, get_requests_by_status,
+        get_requests_by_validation_type, get_requests_by_validator, may_get_request,
+    },
+    types::request::validation_request::ValidationRequestStatus,
     util::aliases::QueryResult,
 };
 
@@ -19,38 +22,211 @@ pub fn query_request_by_id(storage: &dyn Storage, id: String) -> QueryResult {
     to_binary(&may_get_request(storage, id))?.to_ok()
 }
 
-/// Queries the contract's internal [storage](crate::storage::request) for
+/// Queries the contract's internal [storage](crate::storage::request) for the scopes of a
+/// [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder)
+/// with the given id which do not yet have a validation result, either from a given validator
+/// or from the required number of validators.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `id` The ID of the validation request to search for.
+/// * `validator` The bech32 address of a validator, if only its results should be considered.
+pub fn query_outstanding_scopes(
+    storage: &dyn Storage,
+    id: String,
+    validator: Option<Addr>,
+) -> QueryResult {
+    to_binary(
+        &may_get_request(storage, id)
+            .map(|request| request.get_outstanding_scopes(validator.as_ref())),
+    )?
+    .to_ok()
+}
+
+/// Queries the contract's internal [storage](crate::storage::request) for a
+/// [page](crate::types::page::Page) of
 /// [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder)s
 /// with the given owner.
 ///
 /// # Parameters
 ///
-/// * `id` An ID of an owner of a validation request.
-pub fn query_request_by_owner(storage: &dyn Storage, owner: Addr) -> QueryResult {
-    to_binary(&get_requests_by_owner(
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `owner` The bech32 address of an owner of a validation request.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn query_request_by_owner(
+    storage: &dyn Storage,
+    owner: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_requests_by_owner(storage, owner, start_after, limit))?.to_ok()
+}
+
+/// Queries the contract's internal [storage](crate::storage::request) for a
+/// [page](crate::types::page::Page) of
+/// [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder)s
+/// with the given status, optionally narrowed by validation type and owner.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `status` The status of the validation requests to search for.
+/// * `validation_type` The type of validation requested, if the results should be narrowed by it.
+/// * `owner` The bech32 address of an owner of a validation request, if the results should be narrowed by it.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn query_requests_by_status(
+    storage: &dyn Storage,
+    status: ValidationRequestStatus,
+    validation_type: Option<String>,
+    owner: Option<Addr>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_requests_by_status(
         storage,
+        &status,
+        validation_type,
         owner,
-        NO_VALIDATION_REQUEST_SORT,
+        start_after,
+        limit,
     ))?
     .to_ok()
 }
 
-/// Queries the contract's internal [storage](crate::storage::request) for
+/// Queries the contract's internal [storage](crate::storage::request) for a
+/// [page](crate::types::page::Page) of
 /// [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder)s
-/// with the given owner.
+/// with the given validation type.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validation_type` The type of validation requested.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn query_request_by_validation_type(
+    storage: &dyn Storage,
+    validation_type: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_requests_by_validation_type(
+        storage,
+        validation_type,
+        start_after,
+        limit,
+    ))?
+    .to_ok()
+}
+
+/// Queries the contract's internal [storage](crate::storage::request) for a
+/// [page](crate::types::page::Page) of
+/// [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder)s
+/// which list the given validator among their allowed validators.
 ///
 /// # Parameters
 ///
 /// * `storage` An immutable reference to the contract's internal storage.
-/// * `id` An ID of a validator requested for validation.
-pub fn query_request_by_validator(storage: &dyn Storage, validator: Addr) -> QueryResult {
+/// * `validator` The bech32 address of a validator requested for validation.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn query_request_by_validator(
+    storage: &dyn Storage,
+    validator: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
     to_binary(&get_requests_by_validator(
         storage,
         validator,
-        NO_VALIDATION_REQUEST_SORT,
+        start_after,
+        limit,
     ))?
     .to_ok()
 }
 
+/// Queries the contract's internal [storage](crate::storage::request) for a
+/// [page](crate::types::page::Page) of
+/// [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder)s
+/// which allow any address to act as their validator.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn query_open_requests(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_open_requests(storage, start_after, limit))?.to_ok()
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::query_requests_by_status;
+    use crate::{
+        test::{
+            arbitrary::{arb_addr, arb_validation_request},
+            helpers::{
+                set_up_claim, set_up_contract, set_up_request, set_up_validation_definition,
+                set_up_validator_configuration,
+            },
+        },
+        types::{
+            page::Page,
+            request::validation_request::{ValidationRequestOrder, ValidationRequestStatus},
+        },
+    };
+
+    use cosmwasm_std::from_binary;
+    use proptest::{prop_assert_eq, prop_assume, proptest};
+
+    proptest! {
+        #[test]
+        fn query_requests_by_status_with_filters(
+            owner in arb_addr(),
+            other_owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            other_request in arb_validation_request(None),
+        ) {
+            prop_assume!(owner != other_owner);
+            prop_assume!(request.id != other_request.id);
+            prop_assume!(request.validation_type.to_lowercase() != other_request.validation_type.to_lowercase());
+            let mut deps = set_up_contract(&request.validation_type);
+            set_up_validation_definition(&mut deps, &other_request.validation_type, true);
+            set_up_validator_configuration(&mut deps, &validator, &other_request.validation_type, vec![]);
+            let id = request.id.clone();
+            let validation_type = request.validation_type.clone();
+            let other_id = other_request.id.clone();
+            let other_validation_type = other_request.validation_type.clone();
+            set_up_request(&mut deps, &owner, request);
+            set_up_request(&mut deps, &other_owner, other_request);
+            set_up_claim(&mut deps, &validator, &other_id);
+
+            let ids_for = |status, validation_type: Option<String>, owner| -> Vec<String> {
+                let page: Page<ValidationRequestOrder> = from_binary(
+                    &query_requests_by_status(&deps.storage, status, validation_type, owner, None, None).unwrap()
+                ).unwrap();
+                page.items.into_iter().map(|request| request.id).collect()
+            };
+            let requested = ValidationRequestStatus::Requested;
+            prop_assert_eq!(vec![id.clone()], ids_for(requested.clone(), None, None));
+            prop_assert_eq!(vec![id.clone()], ids_for(requested.clone(), Some(validation_type.clone()), None));
+            prop_assert_eq!(vec![id.clone()], ids_for(requested.clone(), None, Some(owner.clone())));
+            prop_assert_eq!(vec![id.clone()], ids_for(requested.clone(), Some(validation_type.clone()), Some(owner.clone())));
+            prop_assert_eq!(Vec::<String>::new(), ids_for(requested.clone(), None, Some(other_owner.clone())));
+            prop_assert_eq!(Vec::<String>::new(), ids_for(requested.clone(), Some(other_validation_type.clone()), None));
+            prop_assert_eq!(Vec::<String>::new(), ids_for(requested, Some(validation_type), Some(other_owner.clone())));
+            let pending = ValidationRequestStatus::Pending;
+            prop_assert_eq!(vec![other_id.clone()], ids_for(pending.clone(), None, None));
+            prop_assert_eq!(vec![other_id.clone()], ids_for(pending.clone(), Some(other_validation_type.clone()), None));
+            prop_assert_eq!(vec![other_id], ids_for(pending, Some(other_validation_type), Some(other_owner)));
+        }
+    }
+}
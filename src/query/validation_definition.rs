@@ -1,5 +1,6 @@
 use crate::{
-    storage::validation_definition::may_get_validation_definition, util::aliases::QueryResult,
+    storage::validation_definition::{get_validation_definitions, may_get_validation_definition},
+    util::aliases::QueryResult,
 };
 
 use cosmwasm_std::{to_binary, Storage};
@@ -17,5 +18,138 @@ pub fn query_definition_by_type(storage: &dyn Storage, validation_type: String)
     to_binary(&may_get_validation_definition(storage, validation_type))?.to_ok()
 }
 
+/// Queries the contract's internal [storage](crate::storage::validation_definition) for a
+/// [page](crate::types::page::Page) of [ValidationDefinition](crate::types::validation_definition::ValidationDefinition)s,
+/// optionally narrowed by whether they are enabled and by a prefix of their display names.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `enabled` Whether the validation definitions should be enabled or not, if the results should be narrowed by it.
+/// * `display_name_prefix` A prefix of the display names of the validation definitions, if the results should be
+///   narrowed by it.
+/// * `start_after` The storage key of the validation definition after which the page should begin, if any.
+/// * `limit` The maximum number of validation definitions to return, if any.
+pub fn query_definitions(
+    storage: &dyn Storage,
+    enabled: Option<bool>,
+    display_name_prefix: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_validation_definitions(
+        storage,
+        enabled,
+        display_name_prefix,
+        start_after,
+        limit,
+    ))?
+    .to_ok()
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::query_definitions;
+    use crate::{
+        storage::validation_definition::{
+            get_validation_definition, insert_validation_definition, update_validation_definition,
+        },
+        types::{page::Page, validation_definition::ValidationDefinition},
+    };
+
+    use cosmwasm_std::{from_binary, Storage};
+    use provwasm_mocks::mock_dependencies;
+
+    #[test]
+    fn query_definitions_with_filters() {
+        let mut deps = mock_dependencies(&[]);
+        for (validation_type, display_name, enabled) in [
+            ("heloc", Some("Full HELOC"), true),
+            ("helocdesk", Some("Desk HELOC"), true),
+            ("helocfull", Some("Full HELOC (legacy)"), false),
+            ("mortgage", None, true),
+        ] {
+            insert_validation_definition(
+                deps.as_mut().storage,
+                &ValidationDefinition {
+                    validation_type: validation_type.to_string(),
+                    display_name: display_name.map(|name| name.to_string()),
+                    enabled,
+                    cancellation_fraction: None,
+                    dispute_period: None,
+                    bound_name: None,
+                },
+            )
+            .unwrap();
+        }
+        let types_for = |storage: &dyn Storage,
+                         enabled,
+                         display_name_prefix: Option<&str>,
+                         start_after: Option<&str>,
+                         limit| {
+            let page: Page<ValidationDefinition> = from_binary(
+                &query_definitions(
+                    storage,
+                    enabled,
+                    display_name_prefix.map(|prefix| prefix.to_string()),
+                    start_after.map(|key| key.to_string()),
+                    limit,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            (
+                page.items
+                    .into_iter()
+                    .map(|definition| definition.validation_type)
+                    .collect::<Vec<_>>(),
+                page.next_start_after,
+            )
+        };
+        assert_eq!(
+            (
+                vec!["heloc".to_string(), "helocdesk".to_string()],
+                Some("helocdesk".to_string())
+            ),
+            types_for(&deps.storage, None, None, None, Some(2)),
+        );
+        assert_eq!(
+            (vec!["helocfull".to_string(), "mortgage".to_string()], None),
+            types_for(&deps.storage, None, None, Some("helocdesk"), Some(2)),
+        );
+        assert_eq!(
+            (
+                vec![
+                    "heloc".to_string(),
+                    "helocdesk".to_string(),
+                    "mortgage".to_string()
+                ],
+                None
+            ),
+            types_for(&deps.storage, Some(true), None, None, None),
+        );
+        assert_eq!(
+            (vec!["heloc".to_string()], None),
+            types_for(&deps.storage, Some(true), Some("Full"), None, None),
+        );
+        assert_eq!(
+            (vec!["mortgage".to_string()], None),
+            types_for(&deps.storage, Some(true), None, Some("helocdesk"), Some(1)),
+        );
+        assert_eq!(
+            (vec!["helocfull".to_string()], None),
+            types_for(&deps.storage, Some(false), None, None, None),
+        );
+        let mut disabled_definition = get_validation_definition(&deps.storage, "heloc").unwrap();
+        disabled_definition.enabled = false;
+        update_validation_definition(deps.as_mut().storage, &disabled_definition).unwrap();
+        assert_eq!(
+            (vec!["heloc".to_string(), "helocfull".to_string()], None),
+            types_for(&deps.storage, Some(false), None, None, None),
+        );
+        assert_eq!(
+            (vec!["helocdesk".to_string(), "mortgage".to_string()], None),
+            types_for(&deps.storage, Some(true), None, None, None),
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    storage::validation_result::{
+        get_validation_results_by_request, get_validation_results_by_validator,
+    },
+    util::aliases::QueryResult,
+};
+
+use cosmwasm_std::{to_binary, Addr, Storage};
+use result_extensions::ResultExtensions;
+
+/// Queries the contract's internal [storage](crate::storage::validation_result) for a
+/// [page](crate::types::page::Page) of [ValidationResult](crate::types::validation_result::ValidationResult)s
+/// submitted for the validation request with the given id.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `request_id` The ID of the validation request to search for results of.
+/// * `start_after` The storage key of the validation result after which the page should begin, if any.
+/// * `limit` The maximum number of validation results to return, if any.
+pub fn query_results_by_request_id(
+    storage: &dyn Storage,
+    request_id: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_validation_results_by_request(
+        storage,
+        request_id,
+        start_after,
+        limit,
+    ))?
+    .to_ok()
+}
+
+/// Queries the contract's internal [storage](crate::storage::validation_result) for a
+/// [page](crate::types::page::Page) of [ValidationResult](crate::types::validation_result::ValidationResult)s
+/// submitted by the given validator.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validator` The bech32 address of the validator to search for results of.
+/// * `start_after` The storage key of the validation result after which the page should begin, if any.
+/// * `limit` The maximum number of validation results to return, if any.
+pub fn query_results_by_validator(
+    storage: &dyn Storage,
+    validator: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_validation_results_by_validator(
+        storage,
+        &validator,
+        start_after,
+        limit,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {}
@@ -0,0 +1,164 @@
+use crate::{
+    storage::validator_configuration::{
+        get_validator_configurations_by_type, get_validator_configurations_by_validator,
+        may_get_validator_configuration,
+    },
+    types::validator_configuration::ValidatorConfiguration,
+    util::aliases::QueryResult,
+};
+
+use cosmwasm_std::{to_binary, Addr, Storage};
+use result_extensions::ResultExtensions;
+
+/// Queries the contract's internal [storage](crate::storage::validator_configuration) for
+/// the [ValidatorConfiguration] of the given validator for the given validation type.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validator` The bech32 address of the validator.
+/// * `validation_type` The type of validation which the configuration pertains to.
+pub fn query_validator_configuration(
+    storage: &dyn Storage,
+    validator: Addr,
+    validation_type: String,
+) -> QueryResult {
+    to_binary(&may_get_validator_configuration(
+        storage,
+        ValidatorConfiguration::generate_storage_key(&validator, &validation_type),
+    ))?
+    .to_ok()
+}
+
+/// Queries the contract's internal [storage](crate::storage::validator_configuration) for a
+/// [page](crate::types::page::Page) of [ValidatorConfiguration]s of the given validator.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validator` The bech32 address of the validator.
+/// * `start_after` The storage key of the validator configuration after which the page should begin, if any.
+/// * `limit` The maximum number of validator configurations to return, if any.
+pub fn query_validator_configurations_by_validator(
+    storage: &dyn Storage,
+    validator: Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_validator_configurations_by_validator(
+        storage,
+        &validator,
+        start_after,
+        limit,
+    ))?
+    .to_ok()
+}
+
+/// Queries the contract's internal [storage](crate::storage::validator_configuration) for a
+/// [page](crate::types::page::Page) of [ValidatorConfiguration]s for the given validation type,
+/// optionally ordered by their total cost in a given denomination.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validation_type` The type of validation which the configurations pertain to.
+/// * `cost_denom` The denomination to order the validator configurations by the total cost in, if any.
+/// * `start_after` The storage key of the validator configuration after which the page should begin, if any.
+/// * `limit` The maximum number of validator configurations to return, if any.
+pub fn query_validator_configurations_by_type(
+    storage: &dyn Storage,
+    validation_type: String,
+    cost_denom: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> QueryResult {
+    to_binary(&get_validator_configurations_by_type(
+        storage,
+        validation_type,
+        cost_denom,
+        start_after,
+        limit,
+    ))?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        query_validator_configuration, query_validator_configurations_by_type,
+        query_validator_configurations_by_validator,
+    };
+    use crate::{
+        test::{
+            arbitrary::arb_entity,
+            helpers::{set_up_validator_configuration, MockOwnedDeps},
+        },
+        types::{
+            page::Page, validation_cost::ValidationCost,
+            validator_configuration::ValidatorConfiguration,
+        },
+    };
+
+    use cosmwasm_std::{from_binary, Addr, Uint128};
+    use proptest::{prop_assert_eq, proptest};
+    use provwasm_mocks::mock_dependencies;
+
+    fn validators_for_type(
+        deps: &MockOwnedDeps,
+        cost_denom: Option<&str>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Page<ValidatorConfiguration> {
+        from_binary(
+            &query_validator_configurations_by_type(
+                &deps.storage,
+                "heloc".to_string(),
+                cost_denom.map(|denom| denom.to_string()),
+                start_after,
+                limit,
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn query_validator_configurations(destination in arb_entity(None)) {
+            let mut deps = mock_dependencies(&[]);
+            let cost = |amount: u128, denom: &str| ValidationCost {
+                amount: Uint128::new(amount),
+                denom: denom.to_string(),
+                destination: destination.clone(),
+            };
+            let (alpha, beta, gamma) = (Addr::unchecked("alpha"), Addr::unchecked("beta"), Addr::unchecked("gamma"));
+            set_up_validator_configuration(&mut deps, &alpha, "heloc", vec![cost(30, "nhash"), cost(5, "usd")]);
+            set_up_validator_configuration(&mut deps, &beta, "heloc", vec![cost(10, "nhash"), cost(15, "nhash")]);
+            set_up_validator_configuration(&mut deps, &gamma, "heloc", vec![cost(1, "usd")]);
+            let alpha_mortgage = set_up_validator_configuration(&mut deps, &alpha, "mortgage", vec![]);
+
+            let single: Option<ValidatorConfiguration> = from_binary(
+                &query_validator_configuration(&deps.storage, alpha.clone(), "mortgage".to_string()).unwrap()
+            ).unwrap();
+            prop_assert_eq!(Some(alpha_mortgage), single);
+
+            let by_validator: Page<ValidatorConfiguration> = from_binary(
+                &query_validator_configurations_by_validator(&deps.storage, alpha.clone(), None, None).unwrap()
+            ).unwrap();
+            prop_assert_eq!(
+                vec!["heloc", "mortgage"],
+                by_validator.items.iter().map(|configuration| configuration.get_validation_type()).collect::<Vec<_>>()
+            );
+
+            let validators = |page: &Page<ValidatorConfiguration>| {
+                page.items.iter().map(|configuration| configuration.validator.clone()).collect::<Vec<_>>()
+            };
+            prop_assert_eq!(vec![alpha.clone(), beta.clone(), gamma.clone()], validators(&validators_for_type(&deps, None, None, None)));
+            let first_page = validators_for_type(&deps, Some("nhash"), None, Some(2));
+            prop_assert_eq!(vec![gamma, beta], validators(&first_page));
+            let second_page = validators_for_type(&deps, Some("nhash"), first_page.next_start_after, Some(2));
+            prop_assert_eq!(vec![alpha], validators(&second_page));
+            prop_assert_eq!(None, second_page.next_start_after);
+        }
+    }
+}
@@ -1,14 +1,23 @@
-use crate::{types::core::error::ContractError, util::aliases::ContractResult};
+use crate::{
+    types::{
+        core::error::ContractError,
+        fee::{ContractFee, FeeDenom, FeeOperation},
+    },
+    util::aliases::ContractResult,
+};
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Storage, Uint128};
+use cosmwasm_std::{from_slice, Addr, Order, Storage, Uint128};
 use cw_storage_plus::Item;
+use semver::Version;
 
 pub const CONTRACT_TYPE: &str = env!("CARGO_CRATE_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-// TODO: Investigate further how namespaces work with migrations and changing the string value
-/// The namespace for the storage of the [ContractInfo].
-const NAMESPACE_CONTRACT_INFO: &str = concat!("contract_info_", env!("CARGO_PKG_VERSION")); // Alternative: use crate const_concat
+/// The namespace for the storage of the [ContractInfo], which must remain stable across contract versions.
+const NAMESPACE_CONTRACT_INFO: &str = "contract_info";
+/// The prefix of the namespaces under which versions of the contract prior to 0.2.0 stored
+/// the [ContractInfo], each suffixed with the contract version which stored it.
+const LEGACY_NAMESPACE_CONTRACT_INFO_PREFIX: &str = "contract_info_";
 /// The contract's storage of the singleton [ContractInfo].
 const CONTRACT_INFO: Item<ContractInfo> = Item::new(NAMESPACE_CONTRACT_INFO);
 
@@ -20,14 +29,15 @@ pub struct ContractInfo {
     pub contract_name: String,
     pub contract_type: String,
     pub contract_version: String,
-    pub create_request_nhash_fee: Uint128, // TODO: Change to map or vec to store all possible contract-imposed fees, add iter() storage accessors
+    /// The fees charged by the contract for its operations, with at most one fee per operation.
+    pub fee_schedule: Vec<ContractFee>,
 }
 impl ContractInfo {
     pub fn new<S1: Into<String>, S2: Into<String>>(
         admin: Addr,
         bind_name: S1,
         contract_name: S2,
-        create_request_nhash_fee: Option<Uint128>,
+        fee_schedule: Vec<ContractFee>,
     ) -> Self {
         Self {
             admin,
@@ -35,7 +45,53 @@ impl ContractInfo {
             contract_name: contract_name.into(),
             contract_type: CONTRACT_TYPE.to_string(),
             contract_version: CONTRACT_VERSION.to_string(),
-            create_request_nhash_fee: create_request_nhash_fee.unwrap_or_else(Uint128::zero),
+            fee_schedule,
+        }
+    }
+    pub fn get_fee_schedule(&self) -> &[ContractFee] {
+        &self.fee_schedule
+    }
+    /// Returns the fee charged for an operation, if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `operation` The operation to find the fee of.
+    pub fn maybe_get_fee(&self, operation: FeeOperation) -> Option<&ContractFee> {
+        self.fee_schedule
+            .iter()
+            .find(|fee| fee.operation == operation)
+    }
+}
+
+/// The configuration data for the contract as stored by versions of the contract prior to 0.2.0,
+/// which charged a single fee for creating validation requests.
+#[cw_serde]
+pub struct LegacyContractInfo {
+    pub admin: Addr,
+    pub bind_name: String,
+    pub contract_name: String,
+    pub contract_type: String,
+    pub contract_version: String,
+    pub create_request_nhash_fee: Uint128,
+}
+impl From<LegacyContractInfo> for ContractInfo {
+    fn from(legacy: LegacyContractInfo) -> Self {
+        Self {
+            admin: legacy.admin,
+            bind_name: legacy.bind_name,
+            contract_name: legacy.contract_name,
+            contract_type: legacy.contract_type,
+            contract_version: legacy.contract_version,
+            fee_schedule: if legacy.create_request_nhash_fee.is_zero() {
+                vec![]
+            } else {
+                vec![ContractFee {
+                    operation: FeeOperation::CreateRequest,
+                    amount: legacy.create_request_nhash_fee,
+                    denom: FeeDenom::Nhash,
+                    recipient: None,
+                }]
+            },
         }
     }
 }
@@ -63,19 +119,122 @@ pub fn may_get_contract_info(store: &dyn Storage) -> Option<ContractInfo> {
     CONTRACT_INFO.may_load(store).unwrap_or(None)
 }
 
+/// Moves the [LegacyContractInfo] stored under a legacy, version-suffixed namespace into the stable
+/// namespace as a [ContractInfo], unless the stable namespace is already populated. If several legacy
+/// namespaces are populated, the one suffixed with the highest version is moved and the others are
+/// removed. Any legacy request creation fee becomes the only entry of the fee schedule. Returns the
+/// legacy namespace which the contract info was moved from, if any.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn move_legacy_contract_info(storage: &mut dyn Storage) -> ContractResult<Option<String>> {
+    if may_get_contract_info(storage).is_some() {
+        return Ok(None);
+    }
+    let prefix = LEGACY_NAMESPACE_CONTRACT_INFO_PREFIX.as_bytes();
+    let mut end = prefix.to_vec();
+    if let Some(last) = end.last_mut() {
+        *last += 1;
+    }
+    let legacy_records = storage
+        .range(Some(prefix), Some(&end), Order::Ascending)
+        .collect::<Vec<_>>();
+    let mut latest_record: Option<(Version, &[u8], &[u8])> = None;
+    for (key, value) in &legacy_records {
+        let namespace = String::from_utf8_lossy(key);
+        let version = namespace[LEGACY_NAMESPACE_CONTRACT_INFO_PREFIX.len()..]
+            .parse::<Version>()
+            .map_err(|e| ContractError::InvalidMigration {
+                message: format!(
+                    "legacy contract info namespace [{}] is not suffixed with a valid version: {}",
+                    namespace, e
+                ),
+            })?;
+        if latest_record
+            .as_ref()
+            .is_none_or(|(latest_version, _, _)| version > *latest_version)
+        {
+            latest_record = Some((version, key, value));
+        }
+    }
+    let (latest_key, latest_value) = match latest_record {
+        Some((_, key, value)) => (key, value),
+        None => return Ok(None),
+    };
+    let legacy_contract_info: LegacyContractInfo = from_slice(latest_value)?;
+    set_contract_info(storage, &legacy_contract_info.into())?;
+    let moved_namespace = String::from_utf8_lossy(latest_key).to_string();
+    for (key, _) in &legacy_records {
+        storage.remove(key);
+    }
+    Ok(Some(moved_namespace))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::instantiate::instantiate_contract;
     use crate::storage::contract_info::{
-        get_contract_info, may_get_contract_info, set_contract_info, CONTRACT_TYPE,
-        CONTRACT_VERSION,
+        get_contract_info, may_get_contract_info, move_legacy_contract_info, set_contract_info,
+        LegacyContractInfo, CONTRACT_TYPE, CONTRACT_VERSION,
     };
     use crate::test::arbitrary::{arb_addr, arb_contract_info, arb_instantiate_msg};
+    use crate::types::core::error::ContractError;
 
     use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{to_vec, Addr, Storage, Uint128};
     use proptest::{prop_assert, prop_assert_eq, proptest};
     use provwasm_mocks::mock_dependencies;
 
+    fn legacy_contract_info(contract_version: &str) -> LegacyContractInfo {
+        LegacyContractInfo {
+            admin: Addr::unchecked("admin"),
+            bind_name: "validation-oracle.pb".to_string(),
+            contract_name: "Validation Oracle".to_string(),
+            contract_type: CONTRACT_TYPE.to_string(),
+            contract_version: contract_version.to_string(),
+            create_request_nhash_fee: Uint128::zero(),
+        }
+    }
+
+    #[test]
+    fn move_legacy_contract_info_takes_highest_version() {
+        let mut deps = mock_dependencies(&[]);
+        for version in ["0.1.10", "0.1.9"] {
+            let namespace = format!("contract_info_{}", version);
+            deps.storage.set(
+                namespace.as_bytes(),
+                &to_vec(&legacy_contract_info(version)).unwrap(),
+            );
+        }
+
+        assert_eq!(
+            Some("contract_info_0.1.10".to_string()),
+            move_legacy_contract_info(&mut deps.storage).unwrap()
+        );
+        assert_eq!(
+            "0.1.10",
+            get_contract_info(&deps.storage).unwrap().contract_version
+        );
+        assert!(deps.storage.get(b"contract_info_0.1.10").is_none());
+        assert!(deps.storage.get(b"contract_info_0.1.9").is_none());
+        assert_eq!(None, move_legacy_contract_info(&mut deps.storage).unwrap());
+    }
+
+    #[test]
+    fn move_legacy_contract_info_rejects_invalid_version_suffix() {
+        let mut deps = mock_dependencies(&[]);
+        deps.storage.set(
+            b"contract_info_latest",
+            &to_vec(&legacy_contract_info("0.1.0")).unwrap(),
+        );
+
+        assert!(matches!(
+            move_legacy_contract_info(&mut deps.storage),
+            Err(ContractError::InvalidMigration { .. })
+        ));
+    }
+
     proptest! {
         #[test]
         fn set_and_get_contract_info_with_valid_data(contract_info in arb_contract_info(true)) { // TODO: Change to individual parameters?
@@ -96,7 +255,7 @@ mod tests {
             prop_assert_eq!(contract_info.contract_name, fetched_contract_info.contract_name);
             prop_assert_eq!(CONTRACT_TYPE, fetched_contract_info.contract_type);
             prop_assert_eq!(CONTRACT_VERSION, fetched_contract_info.contract_version);
-            prop_assert_eq!(contract_info.create_request_nhash_fee, fetched_contract_info.create_request_nhash_fee);
+            prop_assert_eq!(contract_info.fee_schedule, fetched_contract_info.fee_schedule);
         }
 
         #[test]
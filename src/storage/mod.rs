@@ -2,4 +2,5 @@ pub mod contract_info;
 pub mod entity;
 pub mod request;
 pub mod validation_definition;
+pub mod validation_result;
 pub mod validator_configuration;
@@ -1,12 +1,17 @@
-use std::cmp::Ordering;
-
 use crate::{
-    types::{core::error::ContractError, request::validation_request::ValidationRequestOrder},
-    util::aliases::ContractResult,
+    types::{
+        core::error::ContractError,
+        expiration::Expiration,
+        page::Page,
+        request::validation_request::{
+            LegacyValidationRequestOrder, ValidationRequestOrder, ValidationRequestStatus,
+        },
+    },
+    util::{aliases::ContractResult, pagination::form_page},
 };
 
-use cosmwasm_std::{Addr, Order, Storage};
-use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use cosmwasm_std::{Addr, BlockInfo, Order, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Map, MultiIndex, PrefixBound};
 use result_extensions::ResultExtensions;
 
 /// The primary key prefix for the storage of [ValidationRequestOrder]s in an [IndexedMap].
@@ -17,6 +22,51 @@ const NAMESPACE_VALIDATION_REQUESTS_ID_IDX: &str = "request__id";
 const NAMESPACE_VALIDATION_REQUESTS_OWNER_IDX: &str = "request__owner";
 /// The prefix in the [RequestIndices] for indexing [ValidationRequestOrder]s by their status.
 const NAMESPACE_VALIDATION_REQUESTS_STATUS_IDX: &str = "request__status";
+/// The prefix in the [RequestIndices] for indexing [ValidationRequestOrder]s by their validation type.
+const NAMESPACE_VALIDATION_REQUESTS_TYPE_IDX: &str = "request__type";
+/// The prefix in the [RequestIndices] for indexing [ValidationRequestOrder]s by their status and validation type.
+const NAMESPACE_VALIDATION_REQUESTS_STATUS_TYPE_IDX: &str = "request__status_type";
+/// The prefix in the [RequestIndices] for indexing [ValidationRequestOrder]s by their status and owner.
+const NAMESPACE_VALIDATION_REQUESTS_STATUS_OWNER_IDX: &str = "request__status_owner";
+/// The prefix in the [RequestIndices] for indexing [ValidationRequestOrder]s by their status, validation
+/// type, and owner.
+const NAMESPACE_VALIDATION_REQUESTS_STATUS_TYPE_OWNER_IDX: &str = "request__status_type_owner";
+/// The prefix for indexing the IDs of [ValidationRequestOrder]s by each of their allowed validators.
+const NAMESPACE_VALIDATION_REQUESTS_ALLOWED_VALIDATOR_IDX: &str = "request__allowed_validator";
+/// The prefix for indexing the IDs of [ValidationRequestOrder]s which do not restrict their validators.
+const NAMESPACE_VALIDATION_REQUESTS_OPEN_IDX: &str = "request__open";
+/// The prefix for indexing the IDs of open [ValidationRequestOrder]s by the block heights at which they
+/// or their claims expire.
+const NAMESPACE_VALIDATION_REQUESTS_EXPIRING_AT_HEIGHT_IDX: &str = "request__expiring_height";
+/// The prefix for indexing the IDs of open [ValidationRequestOrder]s by the block times at which they
+/// or their claims expire.
+const NAMESPACE_VALIDATION_REQUESTS_EXPIRING_AT_TIME_IDX: &str = "request__expiring_time";
+/// The prefix for indexing the IDs of open [ValidationRequestOrder]s by each validator with an active
+/// claim on them and their validation type.
+const NAMESPACE_VALIDATION_REQUESTS_ACTIVE_CLAIM_IDX: &str = "request__active_claim";
+
+/// An index of the IDs of validation requests keyed by each of their allowed validators, which is
+/// kept in sync with the [requests] by [store_request] and [delete_request_by_id].
+const ALLOWED_VALIDATOR_INDEX: Map<(&Addr, &str), ()> =
+    Map::new(NAMESPACE_VALIDATION_REQUESTS_ALLOWED_VALIDATOR_IDX);
+/// An index of the IDs of validation requests which allow any address to act as their validator,
+/// which is kept in sync with the [requests] by [store_request] and [delete_request_by_id].
+const OPEN_REQUEST_INDEX: Map<&str, ()> = Map::new(NAMESPACE_VALIDATION_REQUESTS_OPEN_IDX);
+/// An index of the IDs of open validation requests keyed by each block height at which they could
+/// expire, either by their own expiration or by that of an active claim, which is kept in sync with
+/// the [requests] by [store_request] and [delete_request_by_id].
+const EXPIRING_AT_HEIGHT_INDEX: Map<(u64, &str), ()> =
+    Map::new(NAMESPACE_VALIDATION_REQUESTS_EXPIRING_AT_HEIGHT_IDX);
+/// An index of the IDs of open validation requests keyed by each block time, in nanoseconds, at which
+/// they could expire, either by their own expiration or by that of an active claim, which is kept in
+/// sync with the [requests] by [store_request] and [delete_request_by_id].
+const EXPIRING_AT_TIME_INDEX: Map<(u64, &str), ()> =
+    Map::new(NAMESPACE_VALIDATION_REQUESTS_EXPIRING_AT_TIME_IDX);
+/// An index of the IDs of open validation requests keyed by each validator which has an active claim
+/// on them and by their validation type, which is kept in sync with the [requests] by [store_request]
+/// and [delete_request_by_id].
+const ACTIVE_CLAIM_INDEX: Map<(&Addr, &str, &str), ()> =
+    Map::new(NAMESPACE_VALIDATION_REQUESTS_ACTIVE_CLAIM_IDX);
 
 /// Defines a collection of [MultiIndex]s for storing [ValidationRequestOrder]s in
 /// a shared primary key namespace.
@@ -24,13 +74,25 @@ pub struct RequestIndices<'a> {
     pub id_index: MultiIndex<'a, String, ValidationRequestOrder, String>,
     pub owner_index: MultiIndex<'a, String, ValidationRequestOrder, String>,
     pub status_index: MultiIndex<'a, String, ValidationRequestOrder, String>,
+    pub type_index: MultiIndex<'a, String, ValidationRequestOrder, String>,
+    pub status_type_index: MultiIndex<'a, (String, String), ValidationRequestOrder, String>,
+    pub status_owner_index: MultiIndex<'a, (String, String), ValidationRequestOrder, String>,
+    pub status_type_owner_index:
+        MultiIndex<'a, (String, String, String), ValidationRequestOrder, String>,
 }
 impl<'a> IndexList<ValidationRequestOrder> for RequestIndices<'a> {
     fn get_indexes(
         &'_ self,
     ) -> Box<dyn Iterator<Item = &'_ dyn Index<ValidationRequestOrder>> + '_> {
-        let v: Vec<&dyn Index<ValidationRequestOrder>> =
-            vec![&self.id_index, &self.owner_index, &self.status_index];
+        let v: Vec<&dyn Index<ValidationRequestOrder>> = vec![
+            &self.id_index,
+            &self.owner_index,
+            &self.status_index,
+            &self.type_index,
+            &self.status_type_index,
+            &self.status_owner_index,
+            &self.status_type_owner_index,
+        ];
         Box::new(v.into_iter())
     }
 }
@@ -53,6 +115,36 @@ fn requests<'a>() -> IndexedMap<'a, &'a [u8], ValidationRequestOrder, RequestInd
             NAMESPACE_VALIDATION_REQUESTS_PK,
             NAMESPACE_VALIDATION_REQUESTS_STATUS_IDX,
         ),
+        type_index: MultiIndex::new(
+            |_pk, request: &ValidationRequestOrder| request.validation_type.clone(),
+            NAMESPACE_VALIDATION_REQUESTS_PK,
+            NAMESPACE_VALIDATION_REQUESTS_TYPE_IDX,
+        ),
+        status_type_index: MultiIndex::new(
+            |_pk, request: &ValidationRequestOrder| {
+                (request.status.to_string(), request.validation_type.clone())
+            },
+            NAMESPACE_VALIDATION_REQUESTS_PK,
+            NAMESPACE_VALIDATION_REQUESTS_STATUS_TYPE_IDX,
+        ),
+        status_owner_index: MultiIndex::new(
+            |_pk, request: &ValidationRequestOrder| {
+                (request.status.to_string(), request.owner.to_string())
+            },
+            NAMESPACE_VALIDATION_REQUESTS_PK,
+            NAMESPACE_VALIDATION_REQUESTS_STATUS_OWNER_IDX,
+        ),
+        status_type_owner_index: MultiIndex::new(
+            |_pk, request: &ValidationRequestOrder| {
+                (
+                    request.status.to_string(),
+                    request.validation_type.clone(),
+                    request.owner.to_string(),
+                )
+            },
+            NAMESPACE_VALIDATION_REQUESTS_PK,
+            NAMESPACE_VALIDATION_REQUESTS_STATUS_TYPE_OWNER_IDX,
+        ),
     };
     IndexedMap::new(NAMESPACE_VALIDATION_REQUESTS_PK, indices)
 }
@@ -122,7 +214,135 @@ pub fn store_request(
         .replace(storage, request.id.as_bytes(), Some(request), old_request)
         .map_err(|e| ContractError::StorageError {
             message: format!("{:?}", e),
-        })
+        })?;
+    if let Some(old_request) = old_request {
+        remove_validator_indices(storage, old_request);
+    }
+    save_validator_indices(storage, request)
+}
+
+/// Rewrites every validation request stored by versions of the contract prior to 0.2.0 as a
+/// [LegacyValidationRequestOrder] in its current format, keyed by its ID, and rebuilds its indices.
+/// Returns the number of validation requests which were rewritten.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+pub fn migrate_legacy_requests(storage: &mut dyn Storage) -> ContractResult<u32> {
+    let legacy_requests =
+        Map::<&[u8], LegacyValidationRequestOrder>::new(NAMESPACE_VALIDATION_REQUESTS_PK)
+            .range_raw(storage, None, None, Order::Ascending)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ContractError::StorageError {
+                message: format!("failed to load legacy validation requests: {:?}", e),
+            })?;
+    for (key, legacy_request) in &legacy_requests {
+        let request: ValidationRequestOrder = legacy_request.clone().into();
+        if key.as_slice() == request.id.as_bytes() {
+            store_request(storage, &request, Some(&request))?;
+        } else {
+            // The stored value cannot be loaded as a current request, so remove it using its conversion
+            requests()
+                .replace(storage, key, None, Some(&request))
+                .map_err(|e| ContractError::StorageError {
+                    message: format!("{:?}", e),
+                })?;
+            store_request(storage, &request, None)?;
+        }
+    }
+    Ok(legacy_requests.len() as u32)
+}
+
+/// Adds a validation request's ID to the indices of its allowed validators, or to the
+/// index of open requests if it does not restrict which addresses may validate it, as
+/// well as to the indices of expiring requests at each point it could expire and to the
+/// index of active claims for each validator still working on it.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `request` The validation request to index.
+fn save_validator_indices(
+    storage: &mut dyn Storage,
+    request: &ValidationRequestOrder,
+) -> ContractResult<()> {
+    match request.maybe_get_allowed_validators() {
+        Some(allowed_validators) => {
+            for validator in allowed_validators {
+                ALLOWED_VALIDATOR_INDEX.save(storage, (validator, request.get_id()), &())?;
+            }
+        }
+        None => OPEN_REQUEST_INDEX.save(storage, request.get_id(), &())?,
+    }
+    for expiration in request.get_pending_expirations() {
+        match expiration {
+            Expiration::AtHeight(height) => {
+                EXPIRING_AT_HEIGHT_INDEX.save(storage, (*height, request.get_id()), &())?
+            }
+            Expiration::AtTime(time) => {
+                EXPIRING_AT_TIME_INDEX.save(storage, (time.nanos(), request.get_id()), &())?
+            }
+        }
+    }
+    if request.is_open() {
+        for claim in request.get_active_claims() {
+            ACTIVE_CLAIM_INDEX.save(
+                storage,
+                (
+                    &claim.validator,
+                    request.get_validation_type(),
+                    request.get_id(),
+                ),
+                &(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Removes a validation request's ID from the indices of its allowed validators, or from
+/// the index of open requests if it does not restrict which addresses may validate it, as
+/// well as from the indices of expiring requests and from the index of active claims.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `request` The validation request to stop indexing.
+fn remove_validator_indices(storage: &mut dyn Storage, request: &ValidationRequestOrder) {
+    match request.maybe_get_allowed_validators() {
+        Some(allowed_validators) => {
+            for validator in allowed_validators {
+                ALLOWED_VALIDATOR_INDEX.remove(storage, (validator, request.get_id()));
+            }
+        }
+        None => OPEN_REQUEST_INDEX.remove(storage, request.get_id()),
+    }
+    let expirations = request.maybe_get_expires_at().into_iter().chain(
+        request
+            .get_claims()
+            .iter()
+            .filter_map(|claim| claim.maybe_get_expires_at()),
+    );
+    for expiration in expirations {
+        match expiration {
+            Expiration::AtHeight(height) => {
+                EXPIRING_AT_HEIGHT_INDEX.remove(storage, (*height, request.get_id()))
+            }
+            Expiration::AtTime(time) => {
+                EXPIRING_AT_TIME_INDEX.remove(storage, (time.nanos(), request.get_id()))
+            }
+        }
+    }
+    for claim in request.get_claims() {
+        ACTIVE_CLAIM_INDEX.remove(
+            storage,
+            (
+                &claim.validator,
+                request.get_validation_type(),
+                request.get_id(),
+            ),
+        );
+    }
 }
 
 /// Finds a validation request by its ID, returning an [Option]
@@ -163,96 +383,239 @@ pub fn get_request<S: Into<String>>(
         })
 }
 
-/// Finds all validation requests made by a given Provenance address.
+/// Finds a page of the validation requests made by a given Provenance address.
 ///
 /// # Parameters
 ///
 /// * `storage` An immutable reference to the contract's internal storage.
 /// * `owner` The bech32 Provenance address of a requestor.
-/// * `compare` An optional function to sort the results by.
-pub fn get_requests_by_owner<
-    S: Into<String>,
-    C: Fn(&ValidationRequestOrder, &ValidationRequestOrder) -> Ordering,
->(
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn get_requests_by_owner<S: Into<String>>(
     storage: &dyn Storage,
     owner: S,
-    compare: Option<C>,
-) -> Vec<ValidationRequestOrder> {
-    let mut requests: Vec<ValidationRequestOrder> = requests()
-        .idx
-        .owner_index
-        .prefix(owner.into())
-        .range(storage, None, None, Order::Ascending)
-        .filter(|result| result.is_ok())
-        .map(|result| result.unwrap().1)
-        .collect();
-    if let Some(comparator) = compare {
-        requests.sort_by(comparator);
-    }
-    requests
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationRequestOrder> {
+    form_page(
+        requests()
+            .idx
+            .owner_index
+            .prefix(owner.into())
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|result| result.ok()),
+        limit,
+    )
 }
 
-/// Finds all validation requests with a given status.
+/// Finds a page of the validation requests with a given status, optionally narrowed
+/// to those of a given validation type and/or made by a given owner. Each combination
+/// of filters is served by its own index, so only matching requests are read.
 ///
 /// # Parameters
 ///
 /// * `storage` An immutable reference to the contract's internal storage.
 /// * `status` A validation request status.
-/// * `compare` An optional function to sort the results by.
-pub fn get_requests_by_status<
-    S: Into<String>,
-    C: Fn(&ValidationRequestOrder, &ValidationRequestOrder) -> Ordering,
->(
+/// * `validation_type` The type of validation requested, if the results should be narrowed by it.
+/// * `owner` The bech32 Provenance address of a requestor, if the results should be narrowed by it.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn get_requests_by_status(
+    storage: &dyn Storage,
+    status: &ValidationRequestStatus,
+    validation_type: Option<String>,
+    owner: Option<Addr>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationRequestOrder> {
+    let indices = requests().idx;
+    let status = status.to_string();
+    let prefix = match (validation_type, owner) {
+        (None, None) => indices.status_index.prefix(status),
+        (Some(validation_type), None) => {
+            indices.status_type_index.prefix((status, validation_type))
+        }
+        (None, Some(owner)) => indices
+            .status_owner_index
+            .prefix((status, owner.to_string())),
+        (Some(validation_type), Some(owner)) => {
+            indices
+                .status_type_owner_index
+                .prefix((status, validation_type, owner.to_string()))
+        }
+    };
+    form_page(
+        prefix
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|result| result.ok()),
+        limit,
+    )
+}
+
+/// Finds all open validation requests of a given validation type on which a given validator
+/// has an active claim, meaning it has yet to submit a result for every scope.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validator` The bech32 Provenance address of the validator which claimed the requests.
+/// * `validation_type` The type of validation requested.
+pub fn get_pending_requests_claimed_by(
     storage: &dyn Storage,
-    status: S,
-    compare: Option<C>,
-) -> Vec<ValidationRequestOrder> {
-    let mut requests: Vec<ValidationRequestOrder> = requests()
-        .idx
-        .status_index
-        .prefix(status.into())
-        .range(storage, None, None, Order::Ascending)
-        .filter(|result| result.is_ok())
-        .map(|result| result.unwrap().1)
-        .collect();
-    if let Some(comparator) = compare {
-        requests.sort_by(comparator);
+    validator: &Addr,
+    validation_type: &str,
+) -> ContractResult<Vec<ValidationRequestOrder>> {
+    ACTIVE_CLAIM_INDEX
+        .prefix((validator, validation_type))
+        .keys(storage, None, None, Order::Ascending)
+        .map(|id| {
+            id.map_err(|e| ContractError::StorageError {
+                message: format!("{:?}", e),
+            })
+            .and_then(|id| get_request(storage, id))
+        })
+        .collect()
+}
+
+/// Finds the open validation requests which have passed their expiration, or whose claim has
+/// passed its expiration, as of a given block. Only the index entries of expirations which have
+/// been reached are read, so the work done is bounded by the limit.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `block` The block against which expirations are checked.
+/// * `limit` The maximum number of validation requests to return.
+pub fn get_expired_requests(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    limit: usize,
+) -> ContractResult<Vec<ValidationRequestOrder>> {
+    let expired_at_height = EXPIRING_AT_HEIGHT_INDEX.prefix_range(
+        storage,
+        None,
+        Some(PrefixBound::inclusive(block.height)),
+        Order::Ascending,
+    );
+    let expired_at_time = EXPIRING_AT_TIME_INDEX.prefix_range(
+        storage,
+        None,
+        Some(PrefixBound::inclusive(block.time.nanos())),
+        Order::Ascending,
+    );
+    let mut expired_ids: Vec<String> = vec![];
+    for entry in expired_at_height.chain(expired_at_time) {
+        if expired_ids.len() >= limit {
+            break;
+        }
+        let ((_, id), _) = entry.map_err(|e| ContractError::StorageError {
+            message: format!("{:?}", e),
+        })?;
+        if !expired_ids.contains(&id) {
+            expired_ids.push(id);
+        }
     }
-    requests
+    expired_ids
+        .into_iter()
+        .map(|id| get_request(storage, id))
+        .collect()
+}
+
+/// Finds a page of the validation requests of a given validation type.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validation_type` The type of validation requested.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn get_requests_by_validation_type<S: Into<String>>(
+    storage: &dyn Storage,
+    validation_type: S,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationRequestOrder> {
+    form_page(
+        requests()
+            .idx
+            .type_index
+            .prefix(validation_type.into())
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|result| result.ok()),
+        limit,
+    )
 }
 
-/// Finds all validation requests which allow validation to be done by a given Provenance address.
+/// Finds a page of the validation requests which explicitly list a given Provenance address among
+/// their allowed validators. Requests which allow any address to act as their validator are excluded,
+/// and can instead be found with [get_open_requests].
 ///
 /// # Parameters
 ///
 /// * `storage` An immutable reference to the contract's internal storage.
 /// * `validator` The bech32 Provenance address of a potential validator.
-/// * `compare` An optional function to sort the results by.
-pub fn get_requests_by_validator<
-    C: Fn(&ValidationRequestOrder, &ValidationRequestOrder) -> Ordering,
->(
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn get_requests_by_validator(
     storage: &dyn Storage,
     validator: Addr,
-    compare: Option<C>,
-) -> Vec<ValidationRequestOrder> {
-    let mut requests: Vec<ValidationRequestOrder> = requests()
-        .idx
-        .owner_index
-        .range(storage, None, None, Order::Ascending)
-        .filter(|result| result.is_ok())
-        .map(|result| result.unwrap().1)
-        .filter(|request| {
-            if let Some(allowed_validators) = request.maybe_get_allowed_validators() {
-                allowed_validators.contains(&validator)
-            } else {
-                false
-            }
-        })
-        .collect();
-    if let Some(comparator) = compare {
-        requests.sort_by(comparator);
-    }
-    requests
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationRequestOrder> {
+    form_page(
+        ALLOWED_VALIDATOR_INDEX
+            .prefix(&validator)
+            .keys(
+                storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|id| id.ok())
+            .filter_map(|id| may_get_request(storage, &id).map(|request| (id, request))),
+        limit,
+    )
+}
+
+/// Finds a page of the validation requests which allow any Provenance address to act as their validator.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `start_after` The ID of the validation request after which the page should begin, if any.
+/// * `limit` The maximum number of validation requests to return, if any.
+pub fn get_open_requests(
+    storage: &dyn Storage,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationRequestOrder> {
+    form_page(
+        OPEN_REQUEST_INDEX
+            .keys(
+                storage,
+                start_after.as_deref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|id| id.ok())
+            .filter_map(|id| may_get_request(storage, &id).map(|request| (id, request))),
+        limit,
+    )
 }
 
 /// Deletes a validation request by its ID, returning a [Result]
@@ -267,6 +630,9 @@ pub fn delete_request_by_id<S: Into<String>>(
     id: S,
 ) -> ContractResult<()> {
     let id = id.into();
+    if let Some(existing_request) = may_get_request(storage, &id) {
+        remove_validator_indices(storage, &existing_request);
+    }
     requests()
         .remove(storage, id.as_bytes())
         .map_err(|e| ContractError::StorageError {
@@ -276,3 +642,219 @@ pub fn delete_request_by_id<S: Into<String>>(
             ),
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        delete_request_by_id, get_expired_requests, get_open_requests,
+        get_pending_requests_claimed_by, get_requests_by_owner, get_requests_by_validator,
+        insert_request, store_request,
+    };
+    use crate::{
+        test::arbitrary::{arb_addr, arb_validation_request},
+        types::{
+            expiration::Expiration,
+            request::validation_request::{
+                ValidationRequestClaim, ValidationRequestOrder, ValidationRequestStatus,
+            },
+        },
+    };
+
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Addr;
+    use proptest::{prop_assert, prop_assert_eq, prop_assume, proptest};
+    use provwasm_mocks::mock_dependencies;
+
+    proptest! {
+        #[test]
+        fn requests_by_owner_are_paged_after_cursor(
+            owner in arb_addr(),
+            request in arb_validation_request(None),
+            count in 1..25usize,
+            limit in 1..10u32,
+        ) {
+            let mut deps = mock_dependencies(&[]);
+            let ids = (0..count).map(|i| format!("{}-{:02}", request.id, i)).collect::<Vec<_>>();
+            for id in &ids {
+                insert_request(deps.as_mut().storage, &ValidationRequestOrder {
+                    id: id.clone(),
+                    owner: owner.clone(),
+                    validation_type: request.validation_type.clone(),
+                    scopes: request.scopes.clone(),
+                    allowed_validators: None,
+                    quote: request.quote.clone(),
+                    expires_at: None,
+                    claim_period: None,
+                    required_validator_count: None,
+                    status: ValidationRequestStatus::Requested,
+                    claims: vec![],
+                    last_release: None,
+                    settlement: None,
+                    dispute: None,
+                    access_definitions: vec![],
+                }).unwrap();
+            }
+
+            let mut paged_ids = vec![];
+            let mut start_after = None;
+            loop {
+                let page = get_requests_by_owner(&deps.storage, owner.clone(), start_after, Some(limit));
+                prop_assert!(page.items.len() <= limit as usize);
+                paged_ids.extend(page.items.into_iter().map(|request| request.id));
+                start_after = page.next_start_after;
+                if start_after.is_none() {
+                    break;
+                }
+            }
+            prop_assert_eq!(ids, paged_ids);
+        }
+
+        #[test]
+        fn validator_indices_follow_allowed_validators(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            other_validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            prop_assume!(validator != other_validator);
+            let mut deps = mock_dependencies(&[]);
+            let open_request = ValidationRequestOrder {
+                id: request.id,
+                owner,
+                validation_type: request.validation_type,
+                scopes: request.scopes,
+                allowed_validators: None,
+                quote: request.quote,
+                expires_at: None,
+                claim_period: None,
+                required_validator_count: None,
+                status: ValidationRequestStatus::Requested,
+                claims: vec![],
+                last_release: None,
+                settlement: None,
+                dispute: None,
+                access_definitions: vec![],
+            };
+            insert_request(deps.as_mut().storage, &open_request).unwrap();
+            prop_assert_eq!(vec![open_request.clone()], get_open_requests(&deps.storage, None, None).items);
+            prop_assert_eq!(0, get_requests_by_validator(&deps.storage, validator.clone(), None, None).items.len());
+
+            let mut restricted_request = open_request.clone();
+            restricted_request.allowed_validators = Some(vec![validator.clone()]);
+            store_request(deps.as_mut().storage, &restricted_request, Some(&open_request)).unwrap();
+            prop_assert_eq!(0, get_open_requests(&deps.storage, None, None).items.len());
+            prop_assert_eq!(vec![restricted_request.clone()], get_requests_by_validator(&deps.storage, validator.clone(), None, None).items);
+            prop_assert_eq!(0, get_requests_by_validator(&deps.storage, other_validator, None, None).items.len());
+
+            delete_request_by_id(deps.as_mut().storage, restricted_request.get_id()).unwrap();
+            prop_assert_eq!(0, get_requests_by_validator(&deps.storage, validator, None, None).items.len());
+        }
+
+        #[test]
+        fn active_claim_index_follows_claims(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            other_validator in arb_addr(),
+            request in arb_validation_request(None),
+        ) {
+            prop_assume!(validator != other_validator);
+            let mut deps = mock_dependencies(&[]);
+            let claim = |validator: &Addr| ValidationRequestClaim {
+                validator: validator.clone(),
+                validation_costs: vec![],
+                expires_at: None,
+                validated_scopes: vec![],
+            };
+            let claimed_request = ValidationRequestOrder {
+                id: request.id,
+                owner,
+                validation_type: request.validation_type.clone(),
+                scopes: request.scopes.clone(),
+                allowed_validators: None,
+                quote: request.quote,
+                expires_at: None,
+                claim_period: None,
+                required_validator_count: Some(2),
+                status: ValidationRequestStatus::Requested,
+                claims: vec![claim(&validator), claim(&other_validator)],
+                last_release: None,
+                settlement: None,
+                dispute: None,
+                access_definitions: vec![],
+            };
+            insert_request(deps.as_mut().storage, &claimed_request).unwrap();
+            prop_assert_eq!(vec![claimed_request.clone()], get_pending_requests_claimed_by(&deps.storage, &validator, &request.validation_type).unwrap());
+            let other_validation_type = format!("{}-other", request.validation_type);
+            prop_assert!(get_pending_requests_claimed_by(&deps.storage, &validator, &other_validation_type).unwrap().is_empty());
+
+            let mut completed_request = claimed_request.clone();
+            completed_request.claims[0].validated_scopes = request.scopes;
+            store_request(deps.as_mut().storage, &completed_request, Some(&claimed_request)).unwrap();
+            prop_assert!(get_pending_requests_claimed_by(&deps.storage, &validator, &request.validation_type).unwrap().is_empty());
+            prop_assert_eq!(vec![completed_request.clone()], get_pending_requests_claimed_by(&deps.storage, &other_validator, &request.validation_type).unwrap());
+
+            let mut fulfilled_request = completed_request.clone();
+            fulfilled_request.status = ValidationRequestStatus::Fulfilled;
+            store_request(deps.as_mut().storage, &fulfilled_request, Some(&completed_request)).unwrap();
+            prop_assert!(get_pending_requests_claimed_by(&deps.storage, &other_validator, &request.validation_type).unwrap().is_empty());
+
+            store_request(deps.as_mut().storage, &completed_request, Some(&fulfilled_request)).unwrap();
+            delete_request_by_id(deps.as_mut().storage, completed_request.get_id()).unwrap();
+            prop_assert!(get_pending_requests_claimed_by(&deps.storage, &other_validator, &request.validation_type).unwrap().is_empty());
+        }
+
+        #[test]
+        fn expired_requests_are_found_by_expiration(
+            owner in arb_addr(),
+            validator in arb_addr(),
+            request in arb_validation_request(None),
+            limit in 1..4usize,
+        ) {
+            let mut deps = mock_dependencies(&[]);
+            let block = mock_env().block;
+            let expiring_request = |id: &str, expires_at, claim_expires_at| ValidationRequestOrder {
+                id: id.to_string(),
+                owner: owner.clone(),
+                validation_type: request.validation_type.clone(),
+                scopes: request.scopes.clone(),
+                allowed_validators: None,
+                quote: request.quote.clone(),
+                expires_at,
+                claim_period: None,
+                required_validator_count: None,
+                status: ValidationRequestStatus::Pending,
+                claims: vec![ValidationRequestClaim {
+                    validator: validator.clone(),
+                    validation_costs: vec![],
+                    expires_at: claim_expires_at,
+                    validated_scopes: vec![],
+                }],
+                last_release: None,
+                settlement: None,
+                dispute: None,
+                access_definitions: vec![],
+            };
+            let expired_requests = [
+                expiring_request("expired-at-height", Some(Expiration::AtHeight(block.height)), Some(Expiration::AtHeight(block.height - 1))),
+                expiring_request("claim-expired-at-height", Some(Expiration::AtHeight(block.height + 1)), Some(Expiration::AtHeight(block.height))),
+                expiring_request("expired-at-time", Some(Expiration::AtTime(block.time)), None),
+            ];
+            let unexpired_requests = [
+                expiring_request("unexpired", Some(Expiration::AtHeight(block.height + 1)), Some(Expiration::AtTime(block.time.plus_seconds(1)))),
+                expiring_request("unexpiring", None, None),
+            ];
+            for request in expired_requests.iter().chain(&unexpired_requests) {
+                insert_request(deps.as_mut().storage, request).unwrap();
+            }
+
+            let found_requests = get_expired_requests(&deps.storage, &block, limit).unwrap();
+            prop_assert_eq!(limit.min(expired_requests.len()), found_requests.len());
+            prop_assert_eq!(&expired_requests[..found_requests.len()], found_requests.as_slice());
+
+            let mut fulfilled_request = expired_requests[0].clone();
+            fulfilled_request.status = ValidationRequestStatus::Fulfilled;
+            store_request(deps.as_mut().storage, &fulfilled_request, Some(&expired_requests[0])).unwrap();
+            prop_assert_eq!(expired_requests[1..].to_vec(), get_expired_requests(&deps.storage, &block, 10).unwrap());
+        }
+    }
+}
@@ -1,25 +1,31 @@
 use crate::{
-    types::{core::error::ContractError, validation_definition::ValidationDefinition},
-    util::aliases::ContractResult,
+    types::{core::error::ContractError, page::Page, validation_definition::ValidationDefinition},
+    util::{
+        aliases::ContractResult, functions::generate_validation_definition_attribute_name,
+        pagination::form_page,
+    },
 };
 
-use cosmwasm_std::Storage;
-use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use cosmwasm_std::{Order, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
 use result_extensions::ResultExtensions;
 
 /// The primary key prefix for the storage of [ValidationDefinition]s in an [IndexedMap].
 const NAMESPACE_VALIDATION_DEFINITIONS_PK: &str = "validation_definition";
 /// The prefix in the [DefinitionIndices] for indexing [ValidationDefinition]s by their validation type.
 const NAMESPACE_VALIDATION_DEFINITIONS_TYPE_IDX: &str = "validation_definition__type";
+/// The prefix in the [DefinitionIndices] for indexing [ValidationDefinition]s by whether they are enabled.
+const NAMESPACE_VALIDATION_DEFINITIONS_ENABLED_IDX: &str = "validation_definition__enabled";
 
 /// Defines a collection of [MultiIndex]s for storing [ValidationDefinition]s in
 /// a shared primary key namespace.
 pub struct DefinitionIndices<'a> {
     pub type_index: MultiIndex<'a, String, ValidationDefinition, String>,
+    pub enabled_index: MultiIndex<'a, String, ValidationDefinition, String>,
 }
 impl<'a> IndexList<ValidationDefinition> for DefinitionIndices<'a> {
     fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ValidationDefinition>> + '_> {
-        let v: Vec<&dyn Index<ValidationDefinition>> = vec![&self.type_index];
+        let v: Vec<&dyn Index<ValidationDefinition>> = vec![&self.type_index, &self.enabled_index];
         Box::new(v.into_iter())
     }
 }
@@ -32,6 +38,11 @@ fn definitions<'a>() -> IndexedMap<'a, &'a [u8], ValidationDefinition, Definitio
             NAMESPACE_VALIDATION_DEFINITIONS_PK,
             NAMESPACE_VALIDATION_DEFINITIONS_TYPE_IDX,
         ),
+        enabled_index: MultiIndex::new(
+            |_pk, definition: &ValidationDefinition| definition.enabled.to_string(),
+            NAMESPACE_VALIDATION_DEFINITIONS_PK,
+            NAMESPACE_VALIDATION_DEFINITIONS_ENABLED_IDX,
+        ),
     };
     IndexedMap::new(NAMESPACE_VALIDATION_DEFINITIONS_PK, indices)
 }
@@ -150,6 +161,112 @@ pub fn may_get_validation_definition<S: Into<String>>(
         .unwrap_or(None)
 }
 
+/// Finds a page of the validation definitions in the contract's storage, optionally narrowed
+/// to those which are or are not enabled and/or whose display names begin with a given prefix.
+/// Narrowing by whether the definitions are enabled is served by an index, while the display
+/// name prefix is checked against each definition read, as index keys cannot be ranged over by
+/// a prefix of a string.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `enabled` Whether the validation definitions should be enabled or not, if the results should be narrowed by it.
+/// * `display_name_prefix` A prefix of the display names of the validation definitions, if the results should be
+///   narrowed by it. Validation definitions without a display name never match a prefix.
+/// * `start_after` The storage key of the validation definition after which the page should begin, if any.
+/// * `limit` The maximum number of validation definitions to return, if any.
+pub fn get_validation_definitions(
+    storage: &dyn Storage,
+    enabled: Option<bool>,
+    display_name_prefix: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationDefinition> {
+    let records: Box<dyn Iterator<Item = (String, ValidationDefinition)>> = match enabled {
+        Some(enabled) => Box::new(
+            definitions()
+                .idx
+                .enabled_index
+                .prefix(enabled.to_string())
+                .range(
+                    storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .filter_map(|result| result.ok()),
+        ),
+        None => Box::new(
+            definitions()
+                .range(
+                    storage,
+                    start_after
+                        .as_deref()
+                        .map(str::as_bytes)
+                        .map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .filter_map(|result| result.ok())
+                .map(|(key, definition)| (String::from_utf8_lossy(&key).to_string(), definition)),
+        ),
+    };
+    form_page(
+        records.filter(|(_, definition)| {
+            display_name_prefix.as_ref().is_none_or(|prefix| {
+                definition
+                    .maybe_get_display_name()
+                    .is_some_and(|display_name| display_name.starts_with(prefix.as_str()))
+            })
+        }),
+        limit,
+    )
+}
+
+/// Finds every validation definition in the contract's storage which has a name bound to the contract.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+pub fn get_bound_validation_definitions(storage: &dyn Storage) -> Vec<ValidationDefinition> {
+    definitions()
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|result| result.ok())
+        .map(|(_, definition)| definition)
+        .filter(|definition| definition.bound_name.is_some())
+        .collect()
+}
+
+/// Records the name bound to the contract for each validation definition in the contract's storage
+/// which has none, as is the case for every definition created before version 0.2.0 of the contract,
+/// and indexes each updated definition by whether it is enabled. Those definitions were bound to a name
+/// under the contract's bind name unless their creation opted out, which was not recorded, so each is
+/// assumed to have been bound. Returns the number of validation definitions which were updated.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `bind_name` The name bound to the contract, under which the validation definitions' names were bound.
+pub fn backfill_bound_names(storage: &mut dyn Storage, bind_name: &str) -> ContractResult<u32> {
+    let unbound_definitions = definitions()
+        .range(storage, None, None, Order::Ascending)
+        .filter_map(|result| result.ok())
+        .map(|(_, definition)| definition)
+        .filter(|definition| definition.bound_name.is_none())
+        .collect::<Vec<_>>();
+    let mut count = 0;
+    for old_definition in unbound_definitions {
+        let mut definition = old_definition.clone();
+        definition.bound_name = Some(generate_validation_definition_attribute_name(
+            &definition.validation_type,
+            bind_name,
+        ));
+        store_validation_definition(storage, &definition, Some(&old_definition))?;
+        count += 1;
+    }
+    count.to_ok()
+}
+
 /// Deletes a validation definition by its key, returning a [Result]
 /// reflecting whether a matching definition was found or not.
 ///
@@ -0,0 +1,320 @@
+use crate::{
+    types::{
+        core::error::ContractError, page::Page,
+        request::validation_request::ValidationRequestOrder, validation_result::ValidationResult,
+    },
+    util::{aliases::ContractResult, pagination::form_page},
+};
+
+use cosmwasm_std::{Addr, Order, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
+use result_extensions::ResultExtensions;
+
+/// The primary key prefix for the storage of [ValidationResult]s in an [IndexedMap].
+const NAMESPACE_VALIDATION_RESULTS_PK: &str = "validation_result";
+/// The prefix in the [ResultIndices] for indexing [ValidationResult]s by the ID of their validation request.
+const NAMESPACE_VALIDATION_RESULTS_REQUEST_IDX: &str = "validation_result__request";
+/// The prefix in the [ResultIndices] for indexing [ValidationResult]s by their validator.
+const NAMESPACE_VALIDATION_RESULTS_VALIDATOR_IDX: &str = "validation_result__validator";
+
+/// Defines a collection of [MultiIndex]s for storing [ValidationResult]s in
+/// a shared primary key namespace.
+pub struct ResultIndices<'a> {
+    pub request_index: MultiIndex<'a, String, ValidationResult, String>,
+    pub validator_index: MultiIndex<'a, String, ValidationResult, String>,
+}
+impl<'a> IndexList<ValidationResult> for ResultIndices<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<ValidationResult>> + '_> {
+        let v: Vec<&dyn Index<ValidationResult>> = vec![&self.request_index, &self.validator_index];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Returns the contract's storage of validation results.
+fn validation_results<'a>() -> IndexedMap<'a, &'a [u8], ValidationResult, ResultIndices<'a>> {
+    let indices = ResultIndices {
+        request_index: MultiIndex::new(
+            |_pk, result: &ValidationResult| result.request_id.clone(),
+            NAMESPACE_VALIDATION_RESULTS_PK,
+            NAMESPACE_VALIDATION_RESULTS_REQUEST_IDX,
+        ),
+        validator_index: MultiIndex::new(
+            |_pk, result: &ValidationResult| result.validator.to_string(),
+            NAMESPACE_VALIDATION_RESULTS_PK,
+            NAMESPACE_VALIDATION_RESULTS_VALIDATOR_IDX,
+        ),
+    };
+    IndexedMap::new(NAMESPACE_VALIDATION_RESULTS_PK, indices)
+}
+
+/// Inserts a validation result into the contract's storage, returning
+/// a [Result] reflecting whether the insertion succeeded or not.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `result` The validation result to insert.
+pub fn insert_validation_result(
+    storage: &mut dyn Storage,
+    result: &ValidationResult,
+) -> ContractResult<()> {
+    let state = validation_results();
+    if let Ok(existing_result) = state.load(storage, result.storage_key().as_bytes()) {
+        ContractError::RecordAlreadyExists {
+            explanation: format!(
+                "a validation result for request [{}] from validator [{}] already exists",
+                existing_result.request_id, existing_result.validator
+            ),
+        }
+        .to_err()
+    } else {
+        store_validation_result(storage, result, None)
+    }
+}
+
+/// Inserts a validation result into the contract's storage, overwriting
+/// any existing validation result with the same key. Returns a [Result]
+/// reflecting whether the insertion succeeded or not.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `result` The validation result to store.
+/// * `old_result` The validation result being replaced, if it exists.
+pub fn store_validation_result(
+    storage: &mut dyn Storage,
+    result: &ValidationResult,
+    old_result: Option<&ValidationResult>,
+) -> ContractResult<()> {
+    validation_results()
+        .replace(
+            storage,
+            result.storage_key().as_bytes(),
+            Some(result),
+            old_result,
+        )
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{:?}", e),
+        })
+}
+
+/// Finds the validation result submitted by a validator for a validation request,
+/// returning an [Option] reflecting whether a matching result was found or not.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `request_id` The ID of the validation request which the result pertains to.
+/// * `validator` The bech32 Provenance address of the validator which submitted the result.
+pub fn may_get_validation_result<S: Into<String>>(
+    storage: &dyn Storage,
+    request_id: S,
+    validator: &Addr,
+) -> Option<ValidationResult> {
+    validation_results()
+        .may_load(
+            storage,
+            ValidationResult::generate_storage_key(&request_id.into(), validator).as_bytes(),
+        )
+        .unwrap_or(None)
+}
+
+/// Removes the validation result submitted by a validator for a validation request from the
+/// contract's storage, if it exists, returning a [Result] reflecting whether the removal succeeded or not.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `request_id` The ID of the validation request which the result pertains to.
+/// * `validator` The bech32 Provenance address of the validator which submitted the result.
+pub fn delete_validation_result<S: Into<String>>(
+    storage: &mut dyn Storage,
+    request_id: S,
+    validator: &Addr,
+) -> ContractResult<()> {
+    let request_id = request_id.into();
+    validation_results()
+        .remove(
+            storage,
+            ValidationResult::generate_storage_key(&request_id, validator).as_bytes(),
+        )
+        .map_err(|e| ContractError::StorageError {
+            message: format!(
+                "failed to remove validation result for request [{}] from validator [{}]: {:?}",
+                request_id, validator, e
+            ),
+        })
+}
+
+/// Removes every validation result submitted for a validation request from the contract's storage,
+/// returning a [Result] reflecting whether the removal succeeded or not.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `request_id` The ID of the validation request which the results pertain to.
+pub fn delete_validation_results_by_request<S: Into<String>>(
+    storage: &mut dyn Storage,
+    request_id: S,
+) -> ContractResult<()> {
+    let request_id = request_id.into();
+    let result_keys = validation_results()
+        .idx
+        .request_index
+        .prefix(request_id.clone())
+        .keys_raw(storage, None, None, Order::Ascending)
+        .collect::<Vec<_>>();
+    for result_key in result_keys {
+        validation_results()
+            .remove(storage, &result_key)
+            .map_err(|e| ContractError::StorageError {
+                message: format!(
+                    "failed to remove validation results for request [{}]: {:?}",
+                    request_id, e
+                ),
+            })?;
+    }
+    Ok(())
+}
+
+/// Finds the largest group of validation results submitted for a validation request by validators
+/// with a complete claim on it which [agree](ValidationResult::agrees_with) with each other.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `request` The validation request which the results pertain to.
+pub fn get_agreeing_validation_results(
+    storage: &dyn Storage,
+    request: &ValidationRequestOrder,
+) -> Vec<ValidationResult> {
+    let complete_results = request
+        .get_claims()
+        .iter()
+        .filter(|claim| request.is_claim_complete(claim))
+        .filter_map(|claim| may_get_validation_result(storage, request.get_id(), &claim.validator))
+        .collect::<Vec<_>>();
+    complete_results
+        .iter()
+        .map(|result| {
+            complete_results
+                .iter()
+                .filter(|other| other.agrees_with(result))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .fold(vec![], |largest, group| {
+            if group.len() > largest.len() {
+                group
+            } else {
+                largest
+            }
+        })
+}
+
+/// Finds a page of the validation results submitted for a given validation request.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `request_id` The ID of a validation request.
+/// * `start_after` The storage key of the validation result after which the page should begin, if any.
+/// * `limit` The maximum number of validation results to return, if any.
+pub fn get_validation_results_by_request<S: Into<String>>(
+    storage: &dyn Storage,
+    request_id: S,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationResult> {
+    form_page(
+        validation_results()
+            .idx
+            .request_index
+            .prefix(request_id.into())
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|result| result.ok()),
+        limit,
+    )
+}
+
+/// Finds a page of the validation results submitted by a given validator.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validator` The bech32 Provenance address of a validator.
+/// * `start_after` The storage key of the validation result after which the page should begin, if any.
+/// * `limit` The maximum number of validation results to return, if any.
+pub fn get_validation_results_by_validator(
+    storage: &dyn Storage,
+    validator: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidationResult> {
+    form_page(
+        validation_results()
+            .idx
+            .validator_index
+            .prefix(validator.to_string())
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|result| result.ok()),
+        limit,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        get_validation_results_by_request, get_validation_results_by_validator,
+        insert_validation_result, may_get_validation_result,
+    };
+    use crate::{
+        test::arbitrary::{arb_addr, arb_scope_validation_result},
+        types::validation_result::ValidationResult,
+    };
+
+    use proptest::collection::vec;
+    use proptest::sample::size_range;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+    use provwasm_mocks::mock_dependencies;
+
+    proptest! {
+        #[test]
+        fn store_and_retrieve_validation_result(
+            request_id in r"\S+",
+            validator in arb_addr(),
+            results_scope_address in arb_addr(),
+            scope_results in vec(arb_scope_validation_result(), size_range(1..10)),
+        ) {
+            let mut deps = mock_dependencies(&[]);
+            let result = ValidationResult {
+                request_id: request_id.clone(),
+                validator: validator.clone(),
+                results_scope_address,
+                scope_results,
+                findings: None,
+            };
+
+            let insertion = insert_validation_result(deps.as_mut().storage, &result);
+            prop_assert!(insertion.is_ok(), "inserting validation result produced an error");
+            prop_assert!(
+                insert_validation_result(deps.as_mut().storage, &result).is_err(),
+                "inserting a duplicate validation result unexpectedly succeeded",
+            );
+
+            prop_assert_eq!(Some(result.clone()), may_get_validation_result(&deps.storage, &request_id, &validator));
+            prop_assert_eq!(vec![result.clone()], get_validation_results_by_request(&deps.storage, &request_id, None, None).items);
+            prop_assert_eq!(vec![result], get_validation_results_by_validator(&deps.storage, &validator, None, None).items);
+        }
+    }
+}
@@ -1,35 +1,41 @@
 use crate::{
-    types::{core::error::ContractError, validator_configuration::ValidatorConfiguration},
-    util::aliases::ContractResult,
+    types::{
+        core::error::ContractError, page::Page, validator_configuration::ValidatorConfiguration,
+    },
+    util::{aliases::ContractResult, pagination::form_page},
 };
 
-use cosmwasm_std::Storage;
-use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex};
+use cosmwasm_std::{Addr, Order, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex};
 use result_extensions::ResultExtensions;
 
 /// The primary key prefix for the storage of [ValidatorConfiguration]s in an [IndexedMap].
 const NAMESPACE_VALIDATOR_CONFIGURATIONS_PK: &str = "validator_configuration";
-/// The prefix in the [DefinitionIndices] for indexing [ValidatorConfiguration]s by their validation type.
+/// The prefix in the [ConfigurationIndices] for indexing [ValidatorConfiguration]s by their validation type.
 const NAMESPACE_VALIDATOR_CONFIGURATIONS_TYPE_IDX: &str = "validator_configuration__type";
+/// The prefix in the [ConfigurationIndices] for indexing [ValidatorConfiguration]s by their validator.
+const NAMESPACE_VALIDATOR_CONFIGURATIONS_VALIDATOR_IDX: &str = "validator_configuration__validator";
 
 /// Defines a collection of [MultiIndex]s for storing [ValidatorConfiguration]s in
 /// a shared primary key namespace.
-pub struct DefinitionIndices<'a> {
+pub struct ConfigurationIndices<'a> {
     pub type_index: MultiIndex<'a, String, ValidatorConfiguration, String>,
+    pub validator_index: MultiIndex<'a, String, ValidatorConfiguration, String>,
 }
-impl<'a> IndexList<ValidatorConfiguration> for DefinitionIndices<'a> {
+impl<'a> IndexList<ValidatorConfiguration> for ConfigurationIndices<'a> {
     fn get_indexes(
         &'_ self,
     ) -> Box<dyn Iterator<Item = &'_ dyn Index<ValidatorConfiguration>> + '_> {
-        let v: Vec<&dyn Index<ValidatorConfiguration>> = vec![&self.type_index];
+        let v: Vec<&dyn Index<ValidatorConfiguration>> =
+            vec![&self.type_index, &self.validator_index];
         Box::new(v.into_iter())
     }
 }
 
 /// Returns the contract's storage of validator configurations.
 fn validator_configurations<'a>(
-) -> IndexedMap<'a, &'a [u8], ValidatorConfiguration, DefinitionIndices<'a>> {
-    let indices = DefinitionIndices {
+) -> IndexedMap<'a, &'a [u8], ValidatorConfiguration, ConfigurationIndices<'a>> {
+    let indices = ConfigurationIndices {
         type_index: MultiIndex::new(
             |_pk, configuration: &ValidatorConfiguration| {
                 configuration.get_validation_type().to_string()
@@ -37,6 +43,11 @@ fn validator_configurations<'a>(
             NAMESPACE_VALIDATOR_CONFIGURATIONS_PK,
             NAMESPACE_VALIDATOR_CONFIGURATIONS_TYPE_IDX,
         ),
+        validator_index: MultiIndex::new(
+            |_pk, configuration: &ValidatorConfiguration| configuration.validator.to_string(),
+            NAMESPACE_VALIDATOR_CONFIGURATIONS_PK,
+            NAMESPACE_VALIDATOR_CONFIGURATIONS_VALIDATOR_IDX,
+        ),
     };
     IndexedMap::new(NAMESPACE_VALIDATOR_CONFIGURATIONS_PK, indices)
 }
@@ -152,3 +163,117 @@ pub fn may_get_validator_configuration<S: Into<String>>(
         .may_load(storage, key.into().as_bytes())
         .unwrap_or(None)
 }
+
+/// Deletes a validator configuration by its key, returning a [Result]
+/// reflecting whether a matching configuration was found or not.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `key` The key of the validator configuration to search for.
+pub fn delete_validator_configuration_by_key<S: Into<String>>(
+    storage: &mut dyn Storage,
+    key: S,
+) -> ContractResult<ValidatorConfiguration> {
+    let state = validator_configurations();
+    let key = key.into();
+    if let Ok(existing_configuration) = state.load(storage, key.as_bytes()) {
+        state
+            .remove(storage, key.as_bytes())
+            .map_err(|e| ContractError::StorageError {
+                message: format!(
+                    "failed to remove validator configuration with key [{}]: {:?}",
+                    key, e
+                ),
+            })?;
+        existing_configuration.to_ok()
+    } else {
+        ContractError::RecordNotFound {
+            explanation: format!(
+                "attempted to delete validator configuration with key [{}] in storage, but no such configuration exists",
+                key
+            ),
+        }
+        .to_err()
+    }
+}
+
+/// Finds a page of the validator configurations of a given validator.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validator` The bech32 Provenance address of a validator.
+/// * `start_after` The storage key of the validator configuration after which the page should begin, if any.
+/// * `limit` The maximum number of validator configurations to return, if any.
+pub fn get_validator_configurations_by_validator(
+    storage: &dyn Storage,
+    validator: &Addr,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidatorConfiguration> {
+    form_page(
+        validator_configurations()
+            .idx
+            .validator_index
+            .prefix(validator.to_string())
+            .range(
+                storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .filter_map(|result| result.ok()),
+        limit,
+    )
+}
+
+/// Finds a page of the validator configurations for a given validation type. If a cost denomination
+/// is given, the configurations are ordered from the lowest to the highest total cost in that
+/// denomination, which requires every configuration for the validation type to be loaded.
+///
+/// # Parameters
+///
+/// * `storage` An immutable reference to the contract's internal storage.
+/// * `validation_type` The type of validation which the validator configurations pertain to.
+/// * `cost_denom` The denomination to order the validator configurations by the total cost in, if any.
+/// * `start_after` The storage key of the validator configuration after which the page should begin, if any.
+/// * `limit` The maximum number of validator configurations to return, if any.
+pub fn get_validator_configurations_by_type<S: Into<String>>(
+    storage: &dyn Storage,
+    validation_type: S,
+    cost_denom: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Page<ValidatorConfiguration> {
+    let configurations = validator_configurations()
+        .idx
+        .type_index
+        .prefix(validation_type.into());
+    match cost_denom {
+        Some(denom) => {
+            let mut records = configurations
+                .range(storage, None, None, Order::Ascending)
+                .filter_map(|result| result.ok())
+                .collect::<Vec<_>>();
+            records.sort_by_cached_key(|(key, configuration)| {
+                (configuration.get_total_cost(&denom), key.clone())
+            });
+            let start = start_after
+                .and_then(|start_after| records.iter().position(|(key, _)| *key == start_after))
+                .map_or(0, |position| position + 1);
+            form_page(records.into_iter().skip(start), limit)
+        }
+        None => form_page(
+            configurations
+                .range(
+                    storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .filter_map(|result| result.ok()),
+            limit,
+        ),
+    }
+}
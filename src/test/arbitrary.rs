@@ -1,9 +1,12 @@
 use crate::storage::contract_info::ContractInfo;
 use crate::types::core::msg::InstantiateMsg;
 use crate::types::entity::EntityDetail;
+use crate::types::fee::{ContractFee, FeeDenom, FeeOperation};
 use crate::types::request::validation_definition::ValidationDefinitionCreationRequest;
+use crate::types::request::validation_request::ValidationRequest;
 use crate::types::validation_cost::ValidationCost;
 use crate::types::validation_definition::ValidationDefinition;
+use crate::types::validation_result::{ScopeValidationResult, ValidationVerdict};
 use crate::types::validator_configuration::ValidatorConfiguration;
 use crate::util::constants::NHASH;
 
@@ -12,10 +15,19 @@ use proptest::collection::vec;
 use proptest::option::of as option_of;
 use proptest::prelude::any;
 use proptest::prop_compose;
+use proptest::prop_oneof;
 use proptest::sample::size_range;
 use proptest::strategy::{Just, Strategy};
 
 const STRING_WITH_NON_WHITESPACE_CHARACTER: &str = r".*\S+.*";
+const FEE_OPERATIONS: [FeeOperation; 6] = [
+    FeeOperation::CreateRequest,
+    FeeOperation::UpdateRequest,
+    FeeOperation::CreateDefinition,
+    FeeOperation::CreateValidatorConfiguration,
+    FeeOperation::AcceptRequest,
+    FeeOperation::SubmitResults,
+];
 
 prop_compose! {
     // TODO: Add random bech32 generation
@@ -37,8 +49,27 @@ prop_compose! {
 }
 
 prop_compose! {
-    pub fn arb_request_creation_nhash_fee()(request_creation_nhash_fee in any::<u128>()) -> Uint128 {
-        Uint128::new(request_creation_nhash_fee)
+    pub fn arb_quote()(
+        coins in vec(("[a-c]", 1..1_000_000_000u128), size_range(0..5)),
+    ) -> Vec<Coin> {
+        coins.into_iter().map(|(denom, amount)| Coin::new(amount, denom)).collect()
+    }
+}
+
+prop_compose! {
+    pub fn arb_fee_schedule()(
+        fees in vec(option_of((any::<u128>(), any::<bool>())), FEE_OPERATIONS.len()),
+    ) -> Vec<ContractFee> {
+        FEE_OPERATIONS
+            .into_iter()
+            .zip(fees)
+            .filter_map(|(operation, fee)| fee.map(|(amount, usd)| ContractFee {
+                operation,
+                amount: Uint128::new(amount),
+                denom: if usd { FeeDenom::Usd } else { FeeDenom::Nhash },
+                recipient: None,
+            }))
+            .collect()
     }
 }
 
@@ -50,10 +81,10 @@ prop_compose! {
         contract_name in ".+",
         random_contract_type in ".+",
         random_contract_version in ".+",
-        create_request_nhash_fee in arb_request_creation_nhash_fee(),
+        fee_schedule in arb_fee_schedule(),
     ) -> ContractInfo {
         if use_package_values {
-            ContractInfo::new(admin, bind_name, contract_name, Some(create_request_nhash_fee))
+            ContractInfo::new(admin, bind_name, contract_name, fee_schedule)
         } else {
             ContractInfo {
                 admin,
@@ -61,7 +92,7 @@ prop_compose! {
                 contract_name,
                 contract_type: random_contract_type,
                 contract_version: random_contract_version,
-                create_request_nhash_fee,
+                fee_schedule,
             }
         }
     }
@@ -125,9 +156,9 @@ prop_compose! {
     pub fn arb_instantiate_msg()(
         bind_name in arb_contract_bind_name(),
         contract_name in r"\S+",
-        create_request_nhash_fee in arb_request_creation_nhash_fee(),
+        fee_schedule in arb_fee_schedule(),
     ) -> InstantiateMsg {
-        InstantiateMsg { bind_name, contract_name, create_request_nhash_fee }
+        InstantiateMsg { bind_name, contract_name, fee_schedule }
     }
 }
 
@@ -149,6 +180,8 @@ prop_compose! {
                 None => random_enabled,
                 value => value,
             },
+            cancellation_fraction: None,
+            dispute_period: None,
         }
     }
 }
@@ -163,6 +196,9 @@ prop_compose! {
             validation_type,
             display_name,
             enabled: enabled.unwrap_or(random_enabled),
+            cancellation_fraction: None,
+            dispute_period: None,
+            bound_name: None,
         }
     }
 }
@@ -185,3 +221,41 @@ prop_compose! {
         ValidatorConfiguration { validation_type, validation_costs, validator }
     }
 }
+
+prop_compose! {
+    pub fn arb_validation_request(allowed_validators: Option<Vec<Addr>>)(
+        id in r"\S+",
+        validation_type in arb_validation_type(),
+        scopes in vec(arb_addr(), size_range(1..10)),
+        quote in arb_quote(),
+    ) -> ValidationRequest {
+        ValidationRequest {
+            id,
+            validation_type,
+            scopes,
+            allowed_validators: allowed_validators.clone(),
+            quote,
+            expires_at: None,
+            claim_period: None,
+            required_validator_count: None,
+            access_routes: None,
+        }
+    }
+}
+
+pub fn arb_validation_verdict() -> impl Strategy<Value = ValidationVerdict> {
+    prop_oneof![
+        Just(ValidationVerdict::Pass),
+        Just(ValidationVerdict::Fail),
+        Just(ValidationVerdict::Inconclusive),
+    ]
+}
+
+prop_compose! {
+    pub fn arb_scope_validation_result()(
+        scope in arb_addr(),
+        verdict in arb_validation_verdict(),
+    ) -> ScopeValidationResult {
+        ScopeValidationResult { scope, verdict }
+    }
+}
@@ -1,10 +1,72 @@
-use cosmwasm_std::testing::{MockApi, MockStorage};
-use cosmwasm_std::{Decimal, OwnedDeps, Response, Uint128};
-use provwasm_mocks::ProvenanceMockQuerier;
+use crate::execute::validation_request::{
+    accept_request_for_validation, create_request_for_validation,
+};
+use crate::storage::contract_info::{set_contract_info, ContractInfo};
+use crate::storage::validation_definition::insert_validation_definition;
+use crate::storage::validator_configuration::insert_validator_configuration;
+use crate::types::request::validation_request::ValidationRequest;
+use crate::types::validation_cost::ValidationCost;
+use crate::types::validation_definition::ValidationDefinition;
+use crate::types::validator_configuration::ValidatorConfiguration;
+
+use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage};
+use cosmwasm_std::{Addr, Decimal, OwnedDeps, Response, Uint128};
+use provwasm_mocks::{mock_dependencies, ProvenanceMockQuerier};
 use provwasm_std::ProvenanceQuery;
 
 pub type MockOwnedDeps = OwnedDeps<MockStorage, MockApi, ProvenanceMockQuerier, ProvenanceQuery>;
 
+pub const TEST_ADMIN: &str = "admin";
+pub const TEST_BIND_NAME: &str = "validation-oracle.pb";
+pub const TEST_CONTRACT_NAME: &str = "Validation Oracle";
+
+pub fn set_up_contract_info(deps: &mut MockOwnedDeps) {
+    set_contract_info(
+        deps.as_mut().storage,
+        &ContractInfo::new(
+            Addr::unchecked(TEST_ADMIN),
+            TEST_BIND_NAME,
+            TEST_CONTRACT_NAME,
+            vec![],
+        ),
+    )
+    .expect("expected contract info to save successfully");
+}
+
+pub fn set_up_validation_definition(
+    deps: &mut MockOwnedDeps,
+    validation_type: &str,
+    enabled: bool,
+) -> ValidationDefinition {
+    let definition = ValidationDefinition {
+        validation_type: validation_type.to_string(),
+        display_name: None,
+        enabled,
+        cancellation_fraction: None,
+        dispute_period: None,
+        bound_name: None,
+    };
+    insert_validation_definition(deps.as_mut().storage, &definition)
+        .expect("expected validation definition to save successfully");
+    definition
+}
+
+pub fn set_up_validator_configuration(
+    deps: &mut MockOwnedDeps,
+    validator: &Addr,
+    validation_type: &str,
+    validation_costs: Vec<ValidationCost>,
+) -> ValidatorConfiguration {
+    let configuration = ValidatorConfiguration {
+        validation_costs,
+        validation_type: validation_type.to_string(),
+        validator: validator.clone(),
+    };
+    insert_validator_configuration(deps.as_mut().storage, &configuration)
+        .expect("expected validator configuration to save successfully");
+    configuration
+}
+
 pub fn single_attribute_for_key<'a, T>(response: &'a Response<T>, key: &'a str) -> &'a str {
     response
         .attributes
@@ -18,3 +80,31 @@ pub fn single_attribute_for_key<'a, T>(response: &'a Response<T>, key: &'a str)
 pub fn decimal(value: u128) -> Decimal {
     Decimal::new(Uint128::new(value))
 }
+
+pub fn set_up_contract(validation_type: &str) -> MockOwnedDeps {
+    let mut deps = mock_dependencies(&[]);
+    set_up_contract_info(&mut deps);
+    set_up_validation_definition(&mut deps, validation_type, true);
+    deps
+}
+
+pub fn set_up_request(deps: &mut MockOwnedDeps, owner: &Addr, request: ValidationRequest) {
+    let quote = request.quote.clone();
+    create_request_for_validation(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(owner.as_str(), &quote),
+        request,
+    )
+    .expect("expected validation request to be created successfully");
+}
+
+pub fn set_up_claim(deps: &mut MockOwnedDeps, validator: &Addr, request_id: &str) {
+    accept_request_for_validation(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(validator.as_str(), &[]),
+        request_id.to_string(),
+    )
+    .expect("expected validation request to be accepted successfully");
+}
@@ -2,11 +2,16 @@ use super::access_route::AccessRoute;
 
 use cosmwasm_schema::cw_serde;
 
+/// A collection of [AccessRoute]s to the underlying asset data of a validation request's scopes, which
+/// is attached to a [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder)
+/// by either its requestor or one of its validators.
 #[cw_serde]
 pub struct AccessDefinition {
-    // TODO: Use this struct
+    /// The bech32 address of the party which attached the access definition.
     pub owner_address: String,
+    /// The routes through which the underlying asset data can be retrieved.
     pub access_routes: Vec<AccessRoute>,
+    /// Denotes which party attached the access definition.
     pub definition_type: AccessDefinitionType,
 }
 impl AccessDefinition {
@@ -16,6 +21,9 @@ impl AccessDefinition {
     pub fn get_access_routes(&self) -> &[AccessRoute] {
         &self.access_routes
     }
+    pub fn get_definition_type(&self) -> &AccessDefinitionType {
+        &self.definition_type
+    }
 }
 
 #[cw_serde]
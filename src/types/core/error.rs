@@ -28,6 +28,14 @@ pub enum ContractError {
         message: String,
     },
 
+    /// An error returned when the quote of a validation request does not cover the
+    /// costs configured by the validator attempting to accept it.
+    #[error("Insufficient quote: {message}")]
+    InsufficientQuote {
+        /// Lists the amount by which the quote falls short of the costs for each denomination.
+        message: String,
+    },
+
     // TODO: Consolidate usage of InvalidRequest versus RecordNotFound for update operations
     /// A generic error returned from a contract entrypoint when a more specific & applicable
     /// variant for indicating some problem with the request input is not defined.
@@ -1,11 +1,15 @@
 use crate::types::{
     entity::EntityDetail,
+    fee::ContractFee,
     request::{
         settings_update::SettingsUpdate,
         validation_definition::{
             ValidationDefinitionCreationRequest, ValidationDefinitionUpdateRequest,
         },
-        validation_request::{ValidationRequest, ValidationRequestUpdate},
+        validation_request::{
+            DisputeResolution, ValidationRequest, ValidationRequestStatus, ValidationRequestUpdate,
+        },
+        validation_result::ValidationResultSubmission,
         validator_configuration::{
             ValidatorConfigurationCreationRequest, ValidatorConfigurationUpdateRequest,
         },
@@ -13,13 +17,14 @@ use crate::types::{
 };
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::Addr;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub bind_name: String,
     pub contract_name: String,
-    pub create_request_nhash_fee: Uint128,
+    #[serde(default)]
+    pub fee_schedule: Vec<ContractFee>,
     // TODO: Add Option<Vec<ValidationDefinitionCreationRequest>> field?
 }
 
@@ -49,9 +54,35 @@ pub enum ExecuteMsg {
     },
     DeleteValidationRequest {
         id: String,
+        force: Option<bool>,
+    },
+    CancelValidationRequest {
+        id: String,
+    },
+    AcceptValidationRequest {
+        id: String,
+    },
+    ReleaseValidationRequest {
+        id: String,
+        reason: String,
+    },
+    ExpireValidationRequests {
+        limit: Option<u32>,
+    },
+    SubmitValidationResults {
+        results: ValidationResultSubmission,
+    },
+    DisputeValidationResult {
+        id: String,
+        reason: String,
+    },
+    ResolveDispute {
+        id: String,
+        resolution: DisputeResolution,
+    },
+    SettleValidationRequest {
+        id: String,
     },
-    //AcceptValidationRequest
-    //SubmitValidationResults
     // TODO: Think about possible flows of updating a definition and updating a configuration
     CreateValidatorConfiguration {
         request: ValidatorConfigurationCreationRequest,
@@ -59,7 +90,11 @@ pub enum ExecuteMsg {
     UpdateValidatorConfiguration {
         request: ValidatorConfigurationUpdateRequest,
     },
-    //DeleteValidatorConfiguration,
+    DeleteValidatorConfiguration {
+        validator: Addr,
+        validation_type: String,
+        force: Option<bool>,
+    },
     UpdateSettings {
         update: SettingsUpdate,
     },
@@ -67,17 +102,88 @@ pub enum ExecuteMsg {
 
 #[cw_serde]
 pub enum QueryMsg {
-    QueryEntityByAddress { address: Addr },
-    QueryValidationDefinitionByType { r#type: String },
-    QueryValidationRequestById { id: String },
-    QueryValidationRequestByOwner { owner: Addr },
-    QueryValidationRequestByValidator { validator: Addr },
-    //QueryValidationResultsBy...
-    //QueryValidatorConfigurationBy...
+    QueryEntityByAddress {
+        address: Addr,
+    },
+    QueryValidationDefinitionByType {
+        r#type: String,
+    },
+    QueryValidationDefinitions {
+        enabled: Option<bool>,
+        display_name_prefix: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidationRequestById {
+        id: String,
+    },
+    QueryOutstandingScopes {
+        id: String,
+        validator: Option<Addr>,
+    },
+    QueryValidationRequestByOwner {
+        owner: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidationRequestByType {
+        r#type: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidationRequestByValidator {
+        validator: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidationRequestsByStatus {
+        status: ValidationRequestStatus,
+        validation_type: Option<String>,
+        owner: Option<Addr>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryOpenValidationRequests {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidationResultsByRequestId {
+        id: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidationResultsByValidator {
+        validator: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidatorConfiguration {
+        validator: Addr,
+        validation_type: String,
+    },
+    QueryValidatorConfigurationsByValidator {
+        validator: Addr,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    QueryValidatorConfigurationsByType {
+        validation_type: String,
+        cost_denom: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     QueryContractInfo {},
+    QueryFeeSchedule {},
+    QueryMigrationDryRun {},
 }
 
 #[cw_serde]
 pub enum MigrateMsg {
-    ContractUpgrade {}, // TODO: Rename, flesh out later
+    // TODO: Rename
+    ContractUpgrade {
+        new_admin_address: Option<String>,
+        new_fee_schedule: Option<Vec<ContractFee>>,
+        new_contract_name: Option<String>,
+        new_bind_name: Option<String>,
+    },
 }
@@ -0,0 +1,81 @@
+use std::fmt::{Display, Formatter, Result};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{BlockInfo, Timestamp};
+
+/// A point in the life of the blockchain after which something is no longer valid.
+#[cw_serde]
+pub enum Expiration {
+    /// Expires once the block height reaches the given height.
+    AtHeight(u64),
+    /// Expires once the block time reaches the given time.
+    AtTime(Timestamp),
+}
+impl Expiration {
+    /// Returns whether or not the expiration has been reached as of the given block.
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        match self {
+            Expiration::AtHeight(height) => block.height >= *height,
+            Expiration::AtTime(time) => block.time >= *time,
+        }
+    }
+}
+impl Display for Expiration {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Expiration::AtHeight(height) => write!(f, "height {}", height),
+            Expiration::AtTime(time) => write!(f, "time {}", time),
+        }
+    }
+}
+
+/// A span of the life of the blockchain, which can be used to determine an [Expiration].
+#[cw_serde]
+pub enum Duration {
+    /// A span of a given number of blocks.
+    Height(u64),
+    /// A span of a given number of seconds.
+    Time(u64),
+}
+impl Duration {
+    /// Returns the [Expiration] which is reached once this span has passed after the given block.
+    pub fn after(&self, block: &BlockInfo) -> Expiration {
+        match self {
+            Duration::Height(blocks) => Expiration::AtHeight(block.height.saturating_add(*blocks)),
+            Duration::Time(seconds) => Expiration::AtTime(block.time.plus_seconds(*seconds)),
+        }
+    }
+}
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Duration::Height(blocks) => write!(f, "{} blocks", blocks),
+            Duration::Time(seconds) => write!(f, "{} seconds", seconds),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::expiration::{Duration, Expiration};
+
+    use cosmwasm_std::testing::mock_env;
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn expiration_is_reached_after_duration(blocks in 1..1_000_000u64, seconds in 1..1_000_000u64) {
+            let mut block = mock_env().block;
+            let height_expiration = Duration::Height(blocks).after(&block);
+            let time_expiration = Duration::Time(seconds).after(&block);
+            prop_assert_eq!(Expiration::AtHeight(block.height + blocks), height_expiration.clone());
+            prop_assert!(!height_expiration.is_expired(&block));
+            prop_assert!(!time_expiration.is_expired(&block));
+
+            block.height += blocks;
+            block.time = block.time.plus_seconds(seconds);
+            prop_assert!(height_expiration.is_expired(&block));
+            prop_assert!(time_expiration.is_expired(&block));
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::util::constants::{NHASH, USD};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+
+/// An operation of the contract for which a [ContractFee] may be charged to the sender.
+#[cw_serde]
+#[derive(Copy, Eq, Hash)]
+pub enum FeeOperation {
+    /// Creating a validation request.
+    CreateRequest,
+    /// Updating a validation request.
+    UpdateRequest,
+    /// Creating a validation definition.
+    CreateDefinition,
+    /// Creating a validator configuration.
+    CreateValidatorConfiguration,
+    /// Accepting a validation request as a validator.
+    AcceptRequest,
+    /// Submitting the results of a validation request as a validator.
+    SubmitResults,
+}
+impl FeeOperation {
+    /// Returns a human-readable description of the operation, which is included in the description
+    /// of any fee charged for it.
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            FeeOperation::CreateRequest => "validation request creation",
+            FeeOperation::UpdateRequest => "validation request update",
+            FeeOperation::CreateDefinition => "validation definition creation",
+            FeeOperation::CreateValidatorConfiguration => "validator configuration creation",
+            FeeOperation::AcceptRequest => "validation request acceptance",
+            FeeOperation::SubmitResults => "validation result submission",
+        }
+    }
+}
+impl Display for FeeOperation {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            FeeOperation::CreateRequest => write!(f, "create_request"),
+            FeeOperation::UpdateRequest => write!(f, "update_request"),
+            FeeOperation::CreateDefinition => write!(f, "create_definition"),
+            FeeOperation::CreateValidatorConfiguration => {
+                write!(f, "create_validator_configuration")
+            }
+            FeeOperation::AcceptRequest => write!(f, "accept_request"),
+            FeeOperation::SubmitResults => write!(f, "submit_results"),
+        }
+    }
+}
+
+/// The denominations in which Provenance Blockchain msgfees can be charged.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum FeeDenom {
+    /// Charged in [nhash](NHASH).
+    Nhash,
+    /// Charged in [usd](USD) mils, i.e. thousandths of a dollar, which are converted to nhash by the chain.
+    Usd,
+}
+impl Display for FeeDenom {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            FeeDenom::Nhash => write!(f, "{}", NHASH),
+            FeeDenom::Usd => write!(f, "{}", USD),
+        }
+    }
+}
+
+/// A fee charged by the contract to the sender of a [FeeOperation].
+#[cw_serde]
+pub struct ContractFee {
+    /// The operation for which the fee is charged.
+    pub operation: FeeOperation,
+    /// The amount of the fee. A fee of zero is never charged.
+    pub amount: Uint128,
+    /// The denomination of the fee.
+    pub denom: FeeDenom,
+    /// The address which receives the fee, or [None] if the fee is sent to the contract admin.
+    pub recipient: Option<Addr>,
+}
+impl ContractFee {
+    pub fn maybe_get_recipient(&self) -> Option<&Addr> {
+        self.recipient.as_ref()
+    }
+}
+impl Display for ContractFee {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}: {}{}", self.operation, self.amount, self.denom)?;
+        if let Some(recipient) = self.maybe_get_recipient() {
+            write!(f, " to {}", recipient)?;
+        }
+        Ok(())
+    }
+}
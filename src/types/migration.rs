@@ -0,0 +1,31 @@
+use cosmwasm_schema::cw_serde;
+
+/// A report of the changes made to the contract's storage by a [migration](crate::migrate::migrate_contract),
+/// which is also produced without persisting any changes by a [dry run](crate::query::migration::query_migration_dry_run).
+#[cw_serde]
+pub struct MigrationReport {
+    /// The contract version stored before the migration.
+    pub from_version: String,
+    /// The contract version stored after the migration.
+    pub to_version: String,
+    /// The legacy namespace which the contract info was moved out of, if it was stored under one.
+    pub legacy_contract_info_namespace: Option<String>,
+    /// A report of each migration step which was run, in the order which they were run.
+    pub steps: Vec<MigrationStepReport>,
+    /// The number of storage keys whose values were written or changed, if counted.
+    pub changed_key_count: Option<u32>,
+    /// The number of storage keys which were removed, if counted.
+    pub removed_key_count: Option<u32>,
+}
+
+/// A report of the changes made to the contract's storage by a single
+/// [migration step](crate::migrate::MigrationStep).
+#[cw_serde]
+pub struct MigrationStepReport {
+    /// The contract version which introduced the storage format produced by the step.
+    pub version: String,
+    /// A description of the purpose of the step.
+    pub description: String,
+    /// Descriptions of the changes which the step made.
+    pub changes: Vec<String>,
+}
@@ -2,7 +2,12 @@ pub mod access_definition;
 pub mod access_route;
 pub mod core;
 pub mod entity;
+pub mod expiration;
+pub mod fee;
+pub mod migration;
+pub mod page;
 pub mod request;
 pub mod validation_cost;
 pub mod validation_definition;
+pub mod validation_result;
 pub mod validator_configuration;
@@ -0,0 +1,19 @@
+use cosmwasm_schema::cw_serde;
+
+/// A single page of items returned from a paginated [query](crate::contract::query).
+#[cw_serde]
+pub struct Page<T> {
+    /// The items on this page, in ascending order of their storage keys.
+    pub items: Vec<T>,
+    /// The value to provide as `start_after` in order to retrieve the next page of items,
+    /// or [None] if there are no further items.
+    pub next_start_after: Option<String>,
+}
+impl<T> Page<T> {
+    pub fn get_items(&self) -> &[T] {
+        &self.items
+    }
+    pub fn maybe_get_next_start_after(&self) -> Option<&str> {
+        self.next_start_after.as_deref()
+    }
+}
@@ -1,4 +1,5 @@
 pub mod settings_update;
 pub mod validation_definition;
 pub mod validation_request;
+pub mod validation_result;
 pub mod validator_configuration;
@@ -1,6 +1,19 @@
+use crate::types::fee::ContractFee;
+
 use cosmwasm_schema::cw_serde;
 
+/// A request to change one or more of the contract's settings. Any field which is omitted leaves
+/// the corresponding setting unchanged.
 #[cw_serde]
 pub struct SettingsUpdate {
+    /// A new bech32 address to use as the contract admin.
     pub new_admin_address: Option<String>,
+    /// A new fee schedule, which replaces the contract's entire existing fee schedule.
+    pub new_fee_schedule: Option<Vec<ContractFee>>,
+    /// A new name for the contract.
+    pub new_contract_name: Option<String>,
+    /// A new name to bind to the contract's address, in place of the current one. The names of all
+    /// bound [validation definitions](crate::types::validation_definition::ValidationDefinition) are
+    /// rebound beneath it.
+    pub new_bind_name: Option<String>,
 }
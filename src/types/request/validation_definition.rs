@@ -1,6 +1,7 @@
-use crate::types::validation_definition::ValidationDefinition;
+use crate::types::{expiration::Duration, validation_definition::ValidationDefinition};
 
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
 
 #[cw_serde]
 pub struct ValidationDefinitionCreationRequest {
@@ -8,6 +9,8 @@ pub struct ValidationDefinitionCreationRequest {
     pub display_name: Option<String>,
     pub enabled: Option<bool>,
     pub bind_name: Option<bool>,
+    pub cancellation_fraction: Option<Decimal>,
+    pub dispute_period: Option<Duration>,
 }
 impl ValidationDefinitionCreationRequest {
     pub fn get_validation_type(&self) -> &str {
@@ -26,6 +29,9 @@ impl From<ValidationDefinitionCreationRequest> for ValidationDefinition {
             validation_type: request.validation_type,
             display_name: request.display_name,
             enabled: request.enabled.unwrap_or(true),
+            cancellation_fraction: request.cancellation_fraction,
+            dispute_period: request.dispute_period,
+            bound_name: None,
         }
     }
 }
@@ -36,6 +42,8 @@ pub struct ValidationDefinitionUpdateRequest {
     pub new_validation_type: Option<String>,
     pub new_display_name: Option<String>,
     pub enabled: Option<bool>,
+    pub new_cancellation_fraction: Option<Decimal>,
+    pub new_dispute_period: Option<Duration>,
     //pub bind_name: Option<bool>, // TODO: How to let them un/re/bind the name? Or maybe let it be more explicit via new_bind_name field?
 }
 impl ValidationDefinitionUpdateRequest {
@@ -77,6 +85,9 @@ impl From<ValidationDefinitionUpdateRequest> for ValidationDefinition {
                 .unwrap_or(request.current_validation_type),
             display_name: request.new_display_name,
             enabled: request.enabled.unwrap_or(true),
+            cancellation_fraction: request.new_cancellation_fraction,
+            dispute_period: request.new_dispute_period,
+            bound_name: None,
         }
     }
 }
@@ -1,7 +1,15 @@
 use std::fmt::{Display, Formatter, Result};
 
+use crate::types::{
+    access_definition::AccessDefinition,
+    access_route::AccessRoute,
+    expiration::{Duration, Expiration},
+    validation_cost::ValidationCost,
+};
+use crate::util::constants::LEGACY_VALIDATION_TYPE;
+
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Coin};
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal};
 
 /// A request for validation to be performed which can be submitted in a contract
 /// [execution](crate::contract::execute).
@@ -10,6 +18,9 @@ pub struct ValidationRequest {
     /// The ID of the validation request. It must be unique among the contract instance's
     /// [ValidationRequestOrder]s.
     pub id: String,
+    /// The type of validation being requested. It must match the validation type of an enabled
+    /// [ValidationDefinition](crate::types::validation_definition::ValidationDefinition).
+    pub validation_type: String,
     /// A list of the Provenance scopes, each denoted by its bech32 address, that are expected
     /// to be validated in order for this request to be fulfilled.
     pub scopes: Vec<Addr>,
@@ -17,13 +28,31 @@ pub struct ValidationRequest {
     /// permitted to fulfill this request. If omitted, the contract will allow any Provenance
     /// address to accept the request as a validator.
     pub allowed_validators: Option<Vec<Addr>>,
-    /// The quote the requestor is offering in exchange for completion of the request.
+    /// The quote the requestor is offering in exchange for completion of the request. The quote
+    /// must be provided as funds when making the request, and is held in escrow by the contract
+    /// until the request is fulfilled or removed.
     pub quote: Vec<Coin>,
+    /// An optional point after which the request expires if it has not been fulfilled, at which
+    /// point its escrowed quote is refunded. If omitted, the request never expires.
+    pub expires_at: Option<Expiration>,
+    /// An optional span of time given to a validator which accepts the request to submit its results,
+    /// after which the request is returned to the marketplace. If omitted, claims never expire.
+    pub claim_period: Option<Duration>,
+    /// An optional number of validators which must independently submit agreeing results in order for
+    /// this request to be fulfilled, between which the quote is split evenly. If omitted, one validator
+    /// is required.
+    pub required_validator_count: Option<u32>,
+    /// An optional list of routes through which validators can retrieve the underlying asset data
+    /// of the scopes, which is stored with the request as an [AccessDefinition] of the requestor.
+    pub access_routes: Option<Vec<AccessRoute>>,
 }
 impl ValidationRequest {
     pub fn get_id(&self) -> &str {
         &self.id
     }
+    pub fn get_validation_type(&self) -> &str {
+        &self.validation_type
+    }
 }
 
 /// A request for validation which is stored as a [queriable](crate::contract::query)
@@ -35,6 +64,9 @@ pub struct ValidationRequestOrder {
     pub id: String,
     /// The bech32 address of the requestor.
     pub owner: Addr,
+    /// The type of validation being requested, matching the validation type of a
+    /// [ValidationDefinition](crate::types::validation_definition::ValidationDefinition).
+    pub validation_type: String,
     /// A list of the Provenance scopes, each denoted by its bech32 address, that are expected
     /// to be validated in order for this request to be fulfilled.
     pub scopes: Vec<Addr>,
@@ -44,13 +76,36 @@ pub struct ValidationRequestOrder {
     pub allowed_validators: Option<Vec<Addr>>,
     /// The quote the requestor is offering in exchange for completion of the request.
     pub quote: Vec<Coin>,
+    /// An optional point after which the request expires if it has not been fulfilled.
+    pub expires_at: Option<Expiration>,
+    /// An optional span of time given to a validator which accepts the request to submit its results.
+    pub claim_period: Option<Duration>,
+    /// An optional number of validators which must independently submit agreeing results in order for
+    /// the request to be fulfilled. If omitted, one validator is required.
+    pub required_validator_count: Option<u32>,
     /// The status of the validation request.
     pub status: ValidationRequestStatus,
+    /// The claims made on the validation request by the validators which have accepted it.
+    #[serde(default)]
+    pub claims: Vec<ValidationRequestClaim>,
+    /// The most recent release of a claim on the validation request, if its claim has ever been released.
+    pub last_release: Option<ValidationRequestRelease>,
+    /// The settlement of the validation request's escrowed quote, once it has been fulfilled.
+    pub settlement: Option<ValidationRequestSettlement>,
+    /// The owner's dispute of the validation request's results, if they have been disputed.
+    pub dispute: Option<ValidationRequestDispute>,
+    /// The [AccessDefinition]s attached to the validation request by its requestor and validators,
+    /// describing where the underlying asset data of its scopes can be retrieved.
+    #[serde(default)]
+    pub access_definitions: Vec<AccessDefinition>,
 }
 impl ValidationRequestOrder {
     pub fn get_id(&self) -> &str {
         &self.id
     }
+    pub fn get_validation_type(&self) -> &str {
+        &self.validation_type
+    }
     pub fn get_scopes(&self) -> &[Addr] {
         &self.scopes
     }
@@ -60,6 +115,321 @@ impl ValidationRequestOrder {
     pub fn get_quote(&self) -> &[Coin] {
         &self.quote
     }
+    pub fn maybe_get_expires_at(&self) -> Option<&Expiration> {
+        self.expires_at.as_ref()
+    }
+    pub fn maybe_get_claim_period(&self) -> Option<&Duration> {
+        self.claim_period.as_ref()
+    }
+    pub fn get_required_validator_count(&self) -> u32 {
+        self.required_validator_count.unwrap_or(1)
+    }
+    pub fn get_claims(&self) -> &[ValidationRequestClaim] {
+        &self.claims
+    }
+    pub fn maybe_get_claim(&self, validator: &Addr) -> Option<&ValidationRequestClaim> {
+        self.claims
+            .iter()
+            .find(|claim| &claim.validator == validator)
+    }
+    pub fn get_validators(&self) -> Vec<&Addr> {
+        self.claims.iter().map(|claim| &claim.validator).collect()
+    }
+    pub fn maybe_get_last_release(&self) -> Option<&ValidationRequestRelease> {
+        self.last_release.as_ref()
+    }
+    pub fn get_access_definitions(&self) -> &[AccessDefinition] {
+        &self.access_definitions
+    }
+    pub fn maybe_get_settlement(&self) -> Option<&ValidationRequestSettlement> {
+        self.settlement.as_ref()
+    }
+    pub fn maybe_get_dispute(&self) -> Option<&ValidationRequestDispute> {
+        self.dispute.as_ref()
+    }
+    /// Returns whether or not the contract still holds the request's escrowed quote, either because
+    /// the request is open or because it was fulfilled but its settlement has not yet been paid.
+    pub fn holds_escrow(&self) -> bool {
+        match self.status {
+            ValidationRequestStatus::Requested | ValidationRequestStatus::Pending => true,
+            ValidationRequestStatus::Fulfilled | ValidationRequestStatus::Disputed => self
+                .maybe_get_settlement()
+                .is_some_and(|settlement| !settlement.paid),
+            ValidationRequestStatus::Expired | ValidationRequestStatus::Cancelled => false,
+        }
+    }
+    /// Returns whether or not the request can still be accepted, claimed, or fulfilled.
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            ValidationRequestStatus::Requested | ValidationRequestStatus::Pending
+        )
+    }
+    /// Returns whether or not a claim on the request has a submitted result for every scope of the request.
+    pub fn is_claim_complete(&self, claim: &ValidationRequestClaim) -> bool {
+        self.scopes
+            .iter()
+            .all(|scope| claim.get_validated_scopes().contains(scope))
+    }
+    /// Returns the claims on the request whose validators have yet to submit a result for every scope.
+    pub fn get_active_claims(&self) -> Vec<&ValidationRequestClaim> {
+        self.claims
+            .iter()
+            .filter(|claim| !self.is_claim_complete(claim))
+            .collect()
+    }
+    /// Sets the status of an open request based on how many validators have submitted agreeing results
+    /// and how many are still working on it. The request is fulfilled once the required number of
+    /// validators agree, and otherwise remains available for acceptance until enough validators have
+    /// claimed it to potentially reach that number.
+    ///
+    /// # Parameters
+    ///
+    /// * `agreeing_validator_count` The size of the largest group of validators which have submitted
+    ///   complete and agreeing results for the request.
+    pub fn refresh_status(&mut self, agreeing_validator_count: u32) {
+        let required_validator_count = self.get_required_validator_count();
+        let needed_validator_count =
+            required_validator_count.saturating_sub(agreeing_validator_count);
+        self.status = if agreeing_validator_count >= required_validator_count {
+            ValidationRequestStatus::Fulfilled
+        } else if self.get_active_claims().len() as u32 >= needed_validator_count {
+            ValidationRequestStatus::Pending
+        } else {
+            ValidationRequestStatus::Requested
+        };
+    }
+    /// Removes a validator's claim from the request, recording the reason the claim was released.
+    /// The request's status should then be [refreshed](Self::refresh_status), as the claim may have
+    /// been the one which kept the request from being available for acceptance.
+    ///
+    /// # Parameters
+    ///
+    /// * `validator` The bech32 address of the validator whose claim is released.
+    /// * `reason` A description of why the claim on the request was released.
+    pub fn release<S: Into<String>>(&mut self, validator: &Addr, reason: S) {
+        if let Some(index) = self
+            .claims
+            .iter()
+            .position(|claim| &claim.validator == validator)
+        {
+            let claim = self.claims.remove(index);
+            self.last_release = Some(ValidationRequestRelease {
+                validator: claim.validator,
+                reason: reason.into(),
+            });
+        }
+    }
+    /// Returns the scopes of the request which do not yet have a result submitted for them, either by
+    /// a given validator or, if none is given, by the required number of validators.
+    ///
+    /// # Parameters
+    ///
+    /// * `validator` The bech32 address of a validator, if only its claim should be considered.
+    pub fn get_outstanding_scopes(&self, validator: Option<&Addr>) -> Vec<Addr> {
+        if self.status == ValidationRequestStatus::Fulfilled {
+            return vec![];
+        }
+        self.scopes
+            .iter()
+            .filter(|scope| match validator {
+                Some(validator) => self
+                    .maybe_get_claim(validator)
+                    .is_none_or(|claim| !claim.get_validated_scopes().contains(scope)),
+                None => {
+                    (self
+                        .claims
+                        .iter()
+                        .filter(|claim| claim.get_validated_scopes().contains(scope))
+                        .count() as u32)
+                        < self.get_required_validator_count()
+                }
+            })
+            .cloned()
+            .collect()
+    }
+    /// Returns whether or not the request has passed its expiration as of the given block.
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.maybe_get_expires_at()
+            .is_some_and(|expires_at| expires_at.is_expired(block))
+    }
+    /// Returns the active claims on the request which have passed their expiration as of the given block.
+    pub fn get_expired_claims(&self, block: &BlockInfo) -> Vec<&ValidationRequestClaim> {
+        self.get_active_claims()
+            .into_iter()
+            .filter(|claim| {
+                claim
+                    .maybe_get_expires_at()
+                    .is_some_and(|expires_at| expires_at.is_expired(block))
+            })
+            .collect()
+    }
+    /// Returns the points at which the request could expire if it is open, consisting of its own
+    /// expiration and those of its active claims.
+    pub fn get_pending_expirations(&self) -> Vec<&Expiration> {
+        if !self.is_open() {
+            return vec![];
+        }
+        self.maybe_get_expires_at()
+            .into_iter()
+            .chain(
+                self.get_active_claims()
+                    .into_iter()
+                    .filter_map(|claim| claim.maybe_get_expires_at()),
+            )
+            .collect()
+    }
+    /// Returns whether or not the given address is permitted to act as a validator for this request.
+    pub fn allows_validator(&self, validator: &Addr) -> bool {
+        match self.maybe_get_allowed_validators() {
+            Some(allowed_validators) => allowed_validators.contains(validator),
+            None => true,
+        }
+    }
+}
+
+/// A [ValidationRequestOrder] as stored by versions of the contract prior to 0.2.0, which did not
+/// record a validation type or any of the request's terms, claims, or access definitions, and did
+/// not collect the quote of a request as funds. Since the contract holds no escrow for such a request,
+/// its quote is cleared when it is converted, and must be funded by updating the request.
+#[cw_serde]
+pub struct LegacyValidationRequestOrder {
+    pub id: String,
+    pub owner: Addr,
+    pub scopes: Vec<Addr>,
+    pub allowed_validators: Option<Vec<Addr>>,
+    pub quote: Vec<Coin>,
+    pub status: ValidationRequestStatus,
+}
+impl From<LegacyValidationRequestOrder> for ValidationRequestOrder {
+    fn from(legacy: LegacyValidationRequestOrder) -> Self {
+        Self {
+            id: legacy.id,
+            owner: legacy.owner,
+            validation_type: LEGACY_VALIDATION_TYPE.to_string(),
+            scopes: legacy.scopes,
+            allowed_validators: legacy.allowed_validators,
+            quote: vec![],
+            expires_at: None,
+            claim_period: None,
+            required_validator_count: None,
+            status: legacy.status,
+            claims: vec![],
+            last_release: None,
+            settlement: None,
+            dispute: None,
+            access_definitions: vec![],
+        }
+    }
+}
+
+/// A claim on a [ValidationRequestOrder] which is made when a validator
+/// [accepts](crate::contract::execute) the request for fulfillment.
+#[cw_serde]
+pub struct ValidationRequestClaim {
+    /// The bech32 address of the validator which accepted the request.
+    pub validator: Addr,
+    /// The costs configured by the validator for the request's validation type at the time it was
+    /// accepted, which are paid out of the request's quote once the request is fulfilled.
+    pub validation_costs: Vec<ValidationCost>,
+    /// An optional point after which the claim expires if the validator has not submitted its results,
+    /// determined from the request's [claim period](ValidationRequestOrder::claim_period).
+    pub expires_at: Option<Expiration>,
+    /// The scopes of the request for which the validator has submitted a result so far.
+    pub validated_scopes: Vec<Addr>,
+}
+impl ValidationRequestClaim {
+    pub fn get_validated_scopes(&self) -> &[Addr] {
+        &self.validated_scopes
+    }
+    pub fn maybe_get_expires_at(&self) -> Option<&Expiration> {
+        self.expires_at.as_ref()
+    }
+    pub fn get_validation_costs(&self) -> &[ValidationCost] {
+        &self.validation_costs
+    }
+}
+
+/// A record of a validator's claim on a [ValidationRequestOrder] having been released, which
+/// returns the request to the marketplace.
+#[cw_serde]
+pub struct ValidationRequestRelease {
+    /// The bech32 address of the validator whose claim was released.
+    pub validator: Addr,
+    /// A description of why the claim was released.
+    pub reason: String,
+}
+impl ValidationRequestRelease {
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// The settlement of a fulfilled [ValidationRequestOrder]'s escrowed quote, which is split
+/// between the validators whose agreeing results fulfilled the request.
+#[cw_serde]
+pub struct ValidationRequestSettlement {
+    /// The bech32 addresses of the validators whose agreeing results fulfilled the request.
+    pub validators: Vec<Addr>,
+    /// An optional point until which the owner of the request may dispute its results, during
+    /// which the escrowed quote is held. If omitted, the results could not be disputed.
+    pub dispute_deadline: Option<Expiration>,
+    /// Whether or not the escrowed quote has been paid out.
+    pub paid: bool,
+}
+impl ValidationRequestSettlement {
+    pub fn get_validators(&self) -> &[Addr] {
+        &self.validators
+    }
+    pub fn maybe_get_dispute_deadline(&self) -> Option<&Expiration> {
+        self.dispute_deadline.as_ref()
+    }
+    /// Returns whether or not the request's results can still be disputed as of the given block.
+    pub fn is_disputable(&self, block: &BlockInfo) -> bool {
+        !self.paid
+            && self
+                .maybe_get_dispute_deadline()
+                .is_some_and(|deadline| !deadline.is_expired(block))
+    }
+}
+
+/// A dispute of a fulfilled [ValidationRequestOrder]'s results which is raised by its owner.
+#[cw_serde]
+pub struct ValidationRequestDispute {
+    /// A description of why the owner disputes the results.
+    pub reason: String,
+    /// The resolution of the dispute reached by the contract admin, once it has been resolved.
+    pub resolution: Option<DisputeResolution>,
+}
+impl ValidationRequestDispute {
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+    pub fn maybe_get_resolution(&self) -> Option<&DisputeResolution> {
+        self.resolution.as_ref()
+    }
+}
+
+/// The possible resolutions of a [dispute](ValidationRequestDispute), which determine how the
+/// escrowed quote of the disputed request is paid out.
+#[cw_serde]
+pub enum DisputeResolution {
+    /// Pays the escrowed quote to the validators, as if the results had not been disputed.
+    PayValidators,
+    /// Refunds the escrowed quote to the owner of the request.
+    RefundOwner,
+    /// Refunds a fraction, between zero and one, of the escrowed quote to the owner of the request,
+    /// and pays the remainder to the validators.
+    Split { owner_fraction: Decimal },
+}
+impl Display for DisputeResolution {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            DisputeResolution::PayValidators => write!(f, "pay_validators"),
+            DisputeResolution::RefundOwner => write!(f, "refund_owner"),
+            DisputeResolution::Split { owner_fraction } => write!(f, "split {}", owner_fraction),
+        }
+    }
 }
 
 /// An update to a request for validation to be performed which can be
@@ -72,6 +442,10 @@ pub struct ValidationRequestUpdate {
     /// An optional new ID to use for the validation request. It must be unique among the contract instance's [ValidationRequestOrder]s.
     /// If omitted, the ID of the existing validation request will not be changed.
     pub new_id: Option<String>,
+    /// An optional new type of validation to request, which must match the validation type of an enabled
+    /// [ValidationDefinition](crate::types::validation_definition::ValidationDefinition). If omitted, the
+    /// validation type of the existing validation request will not be changed.
+    pub new_validation_type: Option<String>,
     /// An optional list of Provenance scopes, each denoted by its bech32 address, that are expected to be validated in order for
     /// this request to be fulfilled. If omitted, the scopes listed on the existing validation request will not be replaced.
     pub new_scopes: Option<Vec<Addr>>,
@@ -80,7 +454,11 @@ pub struct ValidationRequestUpdate {
     pub new_allowed_validators: Option<Vec<Addr>>,
     /// An optional new quote the requestor is offering in exchange for completion of the request. If omitted,
     /// the quote listed on the existing validation request, whether empty or not, will not be replaced.
+    /// Any increase over the existing quote must be provided as funds, and any decrease is refunded to the owner.
     pub new_quote: Option<Vec<Coin>>,
+    /// An optional new list of routes through which validators can retrieve the underlying asset data of the scopes.
+    /// If omitted, the access routes attached to the existing validation request by its requestor will not be replaced.
+    pub new_access_routes: Option<Vec<AccessRoute>>,
 }
 impl ValidationRequestUpdate {
     pub fn get_current_id(&self) -> &str {
@@ -92,6 +470,12 @@ impl ValidationRequestUpdate {
     pub fn get_new_id(&self) -> &str {
         self.new_id.as_deref().unwrap()
     }
+    pub fn maybe_get_new_validation_type(&self) -> Option<&str> {
+        self.new_validation_type.as_deref()
+    }
+    pub fn get_new_validation_type(&self) -> &str {
+        self.new_validation_type.as_deref().unwrap()
+    }
     pub fn maybe_get_new_scopes(&self) -> Option<&[Addr]> {
         self.new_scopes.as_deref()
     }
@@ -127,12 +511,22 @@ pub enum ValidationRequestType {
 pub enum ValidationRequestStatus {
     /// Denotes a validation request which has been submitted but not claimed or completed by any validator.
     Requested,
-    // TODO: Determine if a Pending status is desired or not (depends on if requests target single validator or not) and update all these docs accordingly
-    /// Denotes a validation request which has been submitted and claimed by a validator for fulfillment,
-    /// but has yet to have results submitted.
+    /// Denotes a validation request which has been claimed by one or more validators for fulfillment, enough
+    /// of which are still working on it to reach its required validator count, but which has yet to have
+    /// enough agreeing results submitted. The claiming validators are recorded in the
+    /// [claims](ValidationRequestOrder::claims) of the request.
     Pending,
     /// Denotes a validation request which has had its results submitted.
     Fulfilled,
+    /// Denotes a validation request which was not fulfilled before it expired, and has had
+    /// its escrowed quote refunded to its owner.
+    Expired,
+    /// Denotes a validation request which was cancelled by its owner, and has had its escrowed
+    /// quote refunded, less any compensation paid to the validator which had claimed it.
+    Cancelled,
+    /// Denotes a fulfilled validation request whose results have been disputed by its owner, and
+    /// whose escrowed quote is held until the contract admin resolves the dispute.
+    Disputed,
 }
 impl Display for ValidationRequestStatus {
     fn fmt(&self, f: &mut Formatter) -> Result {
@@ -140,6 +534,37 @@ impl Display for ValidationRequestStatus {
             ValidationRequestStatus::Requested => write!(f, "requested"),
             ValidationRequestStatus::Pending => write!(f, "pending"),
             ValidationRequestStatus::Fulfilled => write!(f, "fulfilled"),
+            ValidationRequestStatus::Expired => write!(f, "expired"),
+            ValidationRequestStatus::Cancelled => write!(f, "cancelled"),
+            ValidationRequestStatus::Disputed => write!(f, "disputed"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::types::request::validation_request::{
+        LegacyValidationRequestOrder, ValidationRequestOrder, ValidationRequestStatus,
+    };
+    use crate::util::constants::LEGACY_VALIDATION_TYPE;
+
+    use cosmwasm_std::{from_slice, Addr};
+
+    #[test]
+    fn legacy_request_order_is_converted() {
+        let legacy_json = br#"{"id":"legacy-request","owner":"requestor","scopes":["scope1"],"allowed_validators":null,"quote":[{"denom":"nhash","amount":"100"}],"status":"requested"}"#;
+        assert!(from_slice::<ValidationRequestOrder>(legacy_json).is_err());
+        let request: ValidationRequestOrder =
+            from_slice::<LegacyValidationRequestOrder>(legacy_json)
+                .unwrap()
+                .into();
+        assert_eq!("legacy-request", request.get_id());
+        assert_eq!(Addr::unchecked("requestor"), request.owner);
+        assert_eq!(LEGACY_VALIDATION_TYPE, request.get_validation_type());
+        assert_eq!(ValidationRequestStatus::Requested, request.status);
+        assert!(request.get_quote().is_empty());
+        assert_eq!(None, request.maybe_get_expires_at());
+        assert!(request.get_claims().is_empty());
+        assert!(request.get_access_definitions().is_empty());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::types::{access_route::AccessRoute, validation_result::ScopeValidationResult};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+/// A submission of the results of a validator's review of a validation request which can be
+/// submitted in a contract [execution](crate::contract::execute).
+#[cw_serde]
+pub struct ValidationResultSubmission {
+    /// The ID of the validation request which the results pertain to.
+    pub request_id: String,
+    /// The bech32 address of the Provenance scope which holds the full validation results.
+    pub results_scope_address: Addr,
+    /// The verdict reached for each scope of the validation request.
+    pub scope_results: Vec<ScopeValidationResult>,
+    /// Optional free-form findings of the validator.
+    pub findings: Option<String>,
+    /// An optional list of routes through which the validator's own copy of the underlying asset data can be
+    /// retrieved, which is stored with the validation request as an
+    /// [AccessDefinition](crate::types::access_definition::AccessDefinition) of the validator.
+    pub access_routes: Option<Vec<AccessRoute>>,
+}
+impl ValidationResultSubmission {
+    pub fn get_request_id(&self) -> &str {
+        &self.request_id
+    }
+    pub fn get_scope_results(&self) -> &[ScopeValidationResult] {
+        &self.scope_results
+    }
+    pub fn maybe_get_access_routes(&self) -> Option<&[AccessRoute]> {
+        self.access_routes.as_deref()
+    }
+}
@@ -46,6 +46,6 @@ impl ValidatorConfigurationUpdateRequest {
         self.validation_costs.as_deref().unwrap()
     }
     pub fn storage_key(&self) -> String {
-        format!("{}-{}", &self.validator, &self.get_validation_type())
+        ValidatorConfiguration::generate_storage_key(&self.validator, self.get_validation_type())
     }
 }
@@ -1,7 +1,7 @@
 use super::entity::EntityDetail;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Coin, Uint128};
 
 #[cw_serde]
 pub struct ValidationCost {
@@ -17,6 +17,12 @@ impl ValidationCost {
     pub fn get_denom(&self) -> &str {
         &self.denom
     }
+    pub fn get_coin(&self) -> Coin {
+        Coin {
+            amount: self.amount,
+            denom: self.denom.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -32,8 +38,9 @@ mod tests {
             fee in arb_coin(),
             destination in arb_entity(None),
         ) {
-            let cost = ValidationCost { amount: fee.amount.clone(), denom: fee.denom.clone(), destination: destination.clone() };
+            let cost = ValidationCost { amount: fee.amount, denom: fee.denom.clone(), destination: destination.clone() };
             prop_assert_eq!(fee.amount.u128(), cost.get_amount());
+            prop_assert_eq!(fee, cost.get_coin());
             prop_assert_eq!(destination, cost.destination);
         }
     }
@@ -1,4 +1,7 @@
+use crate::types::expiration::Duration;
+
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
 
 /// A definition for a validation service which is stored as a [queriable](crate::contract::query) item in the
 /// contract's [storage](crate::storage::request) as the result of [executing](crate::contract::execute)
@@ -14,6 +17,19 @@ pub struct ValidationDefinition {
     /// Whether new [ValidationRequest](crate::types::request::validation_request::ValidationRequest)s
     /// which use this definition can be created or not. Managed by the contract admin.
     pub enabled: bool,
+    /// An optional fraction, between zero and one, of a claimed request's escrowed quote which is paid
+    /// to its validator as compensation if the request is
+    /// [cancelled](crate::execute::validation_request::cancel_request_for_validation).
+    /// If omitted, the validator receives nothing and the entire quote is refunded.
+    pub cancellation_fraction: Option<Decimal>,
+    /// An optional span of time after a request is fulfilled during which its owner may
+    /// [dispute](crate::execute::validation_result::dispute_validation_result) the results,
+    /// and its escrowed quote is held. If omitted, the quote is paid out immediately upon fulfillment.
+    pub dispute_period: Option<Duration>,
+    /// The name bound to the contract's address for the validation definition, under which the
+    /// verdicts of submitted [results](crate::types::validation_result::ValidationResult) are written
+    /// as attributes on each validated scope. If omitted, no name was bound and no attributes are written.
+    pub bound_name: Option<String>,
 }
 impl ValidationDefinition {
     pub fn get_validation_type(&self) -> &str {
@@ -25,8 +41,20 @@ impl ValidationDefinition {
     pub fn get_display_name(&self) -> &str {
         self.display_name.as_ref().unwrap()
     }
+    pub fn get_cancellation_fraction(&self) -> Decimal {
+        self.cancellation_fraction.unwrap_or_default()
+    }
+    pub fn maybe_get_dispute_period(&self) -> Option<&Duration> {
+        self.dispute_period.as_ref()
+    }
+    pub fn maybe_get_bound_name(&self) -> Option<&str> {
+        self.bound_name.as_deref()
+    }
     pub fn storage_key(&self) -> String {
-        self.validation_type.to_lowercase()
+        Self::generate_storage_key(&self.validation_type)
+    }
+    pub fn generate_storage_key(validation_type: &str) -> String {
+        validation_type.to_lowercase()
     }
     pub fn get_storage_key_description() -> String {
         String::from("validation type")
@@ -47,7 +75,7 @@ mod tests {
             display_name in option_of(".+"),
             enabled in any::<bool>(),
         ) {
-            let definition = ValidationDefinition { validation_type: validation_type.clone(), display_name: display_name.clone(), enabled };
+            let definition = ValidationDefinition { validation_type: validation_type.clone(), display_name: display_name.clone(), enabled, cancellation_fraction: None, dispute_period: None, bound_name: None };
             // TODO: Ensure the errors collected by prop_assert are returned somewhere for reporting
             prop_assert_eq!(validation_type.clone(), definition.get_validation_type());
             prop_assert_eq!(display_name, definition.maybe_get_display_name().map(|v| v.to_string()));
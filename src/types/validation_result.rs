@@ -0,0 +1,129 @@
+use std::fmt::{Display, Formatter, Result};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+/// The results of a validator's review of a
+/// [ValidationRequestOrder](crate::types::request::validation_request::ValidationRequestOrder),
+/// which are stored as a [queriable](crate::contract::query) item in the contract's
+/// [storage](crate::storage::validation_result) as the result of [executing](crate::contract::execute)
+/// a [submission](crate::types::request::validation_result::ValidationResultSubmission).
+#[cw_serde]
+pub struct ValidationResult {
+    /// The ID of the validation request which the results pertain to.
+    pub request_id: String,
+    /// The bech32 address of the validator which submitted the results.
+    pub validator: Addr,
+    /// The bech32 address of the Provenance scope which holds the full validation results.
+    pub results_scope_address: Addr,
+    /// The verdict reached for each scope of the validation request.
+    pub scope_results: Vec<ScopeValidationResult>,
+    /// Optional free-form findings of the validator.
+    pub findings: Option<String>,
+}
+impl ValidationResult {
+    pub fn get_request_id(&self) -> &str {
+        &self.request_id
+    }
+    pub fn get_scope_results(&self) -> &[ScopeValidationResult] {
+        &self.scope_results
+    }
+    pub fn maybe_get_findings(&self) -> Option<&str> {
+        self.findings.as_deref()
+    }
+    pub fn get_findings(&self) -> &str {
+        self.findings.as_ref().unwrap()
+    }
+    /// Returns whether or not this result reached the same verdict as another for every scope.
+    pub fn agrees_with(&self, other: &ValidationResult) -> bool {
+        self.scope_results.len() == other.scope_results.len()
+            && self
+                .scope_results
+                .iter()
+                .all(|scope_result| other.scope_results.contains(scope_result))
+    }
+    pub fn storage_key(&self) -> String {
+        Self::generate_storage_key(&self.request_id, &self.validator)
+    }
+    pub fn generate_storage_key(request_id: &str, validator: &Addr) -> String {
+        format!("{}-{}", request_id, validator)
+    }
+}
+
+/// The verdict reached by a validator for a single scope of a validation request, which is written
+/// as a JSON attribute on the scope under the name bound for its
+/// [validation definition](crate::types::validation_definition::ValidationDefinition::bound_name).
+#[cw_serde]
+pub struct ScopeValidationAttribute {
+    /// The ID of the validation request which the verdict pertains to.
+    pub request_id: String,
+    /// The bech32 address of the validator which reached the verdict.
+    pub validator: Addr,
+    /// The bech32 address of the Provenance scope which holds the full validation results.
+    pub results_scope_address: Addr,
+    /// The verdict reached for the scope.
+    pub verdict: ValidationVerdict,
+}
+
+/// The verdict reached by a validator for a single scope of a validation request.
+#[cw_serde]
+pub struct ScopeValidationResult {
+    /// The bech32 address of the validated scope.
+    pub scope: Addr,
+    /// The verdict reached for the scope.
+    pub verdict: ValidationVerdict,
+}
+
+/// The possible outcomes of validating a scope.
+#[cw_serde]
+pub enum ValidationVerdict {
+    /// Denotes a scope which passed validation.
+    Pass,
+    /// Denotes a scope which failed validation.
+    Fail,
+    /// Denotes a scope for which the validator could not reach a conclusion.
+    Inconclusive,
+}
+impl Display for ValidationVerdict {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            ValidationVerdict::Pass => write!(f, "pass"),
+            ValidationVerdict::Fail => write!(f, "fail"),
+            ValidationVerdict::Inconclusive => write!(f, "inconclusive"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::arbitrary::{arb_addr, arb_scope_validation_result};
+    use crate::types::validation_result::ValidationResult;
+
+    use proptest::collection::vec;
+    use proptest::option::of as option_of;
+    use proptest::sample::size_range;
+    use proptest::{prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn set_and_get_validation_result(
+            request_id in r"\S+",
+            validator in arb_addr(),
+            results_scope_address in arb_addr(),
+            scope_results in vec(arb_scope_validation_result(), size_range(1..10)),
+            findings in option_of(".+"),
+        ) {
+            let result = ValidationResult {
+                request_id: request_id.clone(),
+                validator: validator.clone(),
+                results_scope_address,
+                scope_results: scope_results.clone(),
+                findings: findings.clone(),
+            };
+            prop_assert_eq!(request_id.clone(), result.get_request_id());
+            prop_assert_eq!(scope_results, result.get_scope_results());
+            prop_assert_eq!(findings, result.maybe_get_findings().map(|v| v.to_string()));
+            prop_assert_eq!(format!("{}-{}", request_id, validator), result.storage_key());
+        }
+    }
+}
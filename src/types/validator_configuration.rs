@@ -1,7 +1,7 @@
 use super::validation_cost::ValidationCost;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint128};
 
 #[cw_serde]
 pub struct ValidatorConfiguration {
@@ -16,8 +16,20 @@ impl ValidatorConfiguration {
     pub fn get_validation_costs(&self) -> &[ValidationCost] {
         &self.validation_costs
     }
+    /// Returns the sum of the validator's costs in the given denomination.
+    pub fn get_total_cost(&self, denom: &str) -> Uint128 {
+        self.validation_costs
+            .iter()
+            .filter(|cost| cost.get_denom() == denom)
+            .fold(Uint128::zero(), |total, cost| {
+                total.saturating_add(cost.amount)
+            })
+    }
     pub fn storage_key(&self) -> String {
-        format!("{}-{}", &self.validator, &self.get_validation_type())
+        Self::generate_storage_key(&self.validator, self.get_validation_type())
+    }
+    pub fn generate_storage_key(validator: &Addr, validation_type: &str) -> String {
+        format!("{}-{}", validator, validation_type)
     }
 }
 
@@ -2,6 +2,18 @@
 
 /// The [Coin](cosmwasm_std::Coin) denomination corresponding to one-billionth of a single hash.
 pub const NHASH: &str = "nhash";
+/// The [Coin](cosmwasm_std::Coin) denomination corresponding to one-thousandth of a US dollar, in which
+/// Provenance Blockchain msgfees can be charged.
+pub const USD: &str = "usd";
+
+/// The number of items returned in a page of a paginated query when no limit is specified.
+pub const DEFAULT_PAGE_LIMIT: u32 = 10;
+/// The maximum number of items which may be returned in a page of a paginated query.
+pub const MAX_PAGE_LIMIT: u32 = 30;
+/// The validation type given to validation requests stored by versions of the contract prior to 0.2.0,
+/// which did not record one. Such requests can be accepted once a
+/// [validation definition](crate::types::validation_definition::ValidationDefinition) of this type is created.
+pub const LEGACY_VALIDATION_TYPE: &str = "legacy";
 
 ///// Shared output attributes
 
@@ -27,6 +39,13 @@ pub const ADDITIONAL_METADATA_KEY: &str = "vo_additional_metadata";
 
 ///// Other output attributes
 
+/// Value = The amount of a [contract fee](crate::types::fee::ContractFee) charged for the operation.
+pub const CONTRACT_FEE_CHARGED_KEY: &str = "vo_contract_fee_charged";
+/// Value = The amount of the [contract fee](crate::types::fee::ContractFee) charged for creating a validation
+/// request. Emitted alongside [CONTRACT_FEE_CHARGED_KEY] so that existing event stream consumers of this key,
+/// which predates the fee schedule, continue to receive it.
+pub const REQUEST_CREATION_FEE_CHARGED_KEY: &str = "request_creation_fee_charged";
+
 /// Value = The current stored [ContractInfo](crate::storage::contract_info::ContractInfo).
 pub const CONTRACT_INFO_KEY: &str = "vo_contract_info";
 
@@ -1,8 +1,17 @@
+use std::collections::HashSet;
+
 use super::aliases::DepsMutC;
 use super::fees::generate_contract_fee_msg;
+use crate::storage::validation_definition::may_get_validation_definition;
+use crate::types::fee::FeeOperation;
 use crate::types::request::validation_request::{
     ValidationRequestOrder, ValidationRequestStatus, ValidationRequestType,
 };
+use crate::types::validation_definition::ValidationDefinition;
+use crate::types::{
+    access_definition::{AccessDefinition, AccessDefinitionType},
+    access_route::AccessRoute,
+};
 use crate::types::{core::error::ContractError, request::validation_request::ValidationRequest};
 
 use cosmwasm_std::{CosmosMsg, Env, MessageInfo};
@@ -22,25 +31,78 @@ pub fn form_validation_request(
     request: ValidationRequest,
     request_type: ValidationRequestType,
 ) -> Result<ValidationRequestCreationResponse, ContractError> {
-    let request_fee_msg = match request_type {
-        ValidationRequestType::New => generate_contract_fee_msg(
-            "validation request creation",
-            &deps.as_ref(),
-            env.contract.address.clone(),
-            |c| c.create_request_nhash_fee.u128(),
-        )?,
-        ValidationRequestType::Update => None,
-    };
+    // The request must pertain to exactly one enabled validation definition
+    match may_get_validation_definition(
+        deps.storage,
+        ValidationDefinition::generate_storage_key(request.get_validation_type()),
+    ) {
+        None => {
+            return ContractError::InvalidRequest {
+                message: format!(
+                    "no validation definition with validation type [{}] exists",
+                    request.get_validation_type()
+                ),
+            }
+            .to_err()
+        }
+        Some(definition) if !definition.enabled => {
+            return ContractError::InvalidRequest {
+                message: format!(
+                    "the validation definition with validation type [{}] is not enabled",
+                    request.get_validation_type()
+                ),
+            }
+            .to_err()
+        }
+        Some(_) => {}
+    }
+    let request_fee_msg = generate_contract_fee_msg(
+        match request_type {
+            ValidationRequestType::New => FeeOperation::CreateRequest,
+            ValidationRequestType::Update => FeeOperation::UpdateRequest,
+        },
+        &deps.as_ref(),
+        env.contract.address.clone(),
+    )?;
     let messages = vec![];
     let request_order = ValidationRequestOrder {
         id: request.id,
         owner: info.sender.clone(),
+        validation_type: request.validation_type,
         scopes: request.scopes,
         allowed_validators: request.allowed_validators,
         quote: request.quote,
+        expires_at: request.expires_at,
+        claim_period: request.claim_period,
+        required_validator_count: request.required_validator_count,
         status: ValidationRequestStatus::Requested,
+        claims: vec![],
+        last_release: None,
+        settlement: None,
+        dispute: None,
+        access_definitions: request
+            .access_routes
+            .filter(|access_routes| !access_routes.is_empty())
+            .map(|access_routes| AccessDefinition {
+                owner_address: info.sender.to_string(),
+                access_routes,
+                definition_type: AccessDefinitionType::Requestor,
+            })
+            .into_iter()
+            .collect(),
     };
     validate_request_order(&request_order)?;
+    if let Some(expires_at) = request_order.maybe_get_expires_at() {
+        if expires_at.is_expired(&env.block) {
+            return ContractError::InvalidRequest {
+                message: format!(
+                    "request order expires at [{}], which has already passed",
+                    expires_at
+                ),
+            }
+            .to_err();
+        }
+    }
     ValidationRequestCreationResponse {
         request_order,
         messages,
@@ -57,9 +119,31 @@ pub fn validate_request_order(request_order: &ValidationRequestOrder) -> Result<
     if request_order.owner.to_string().is_empty() {
         errors.push("request order is missing owner".to_string());
     }
+    if request_order.validation_type.is_empty() {
+        errors.push("request order is missing validation type".to_string());
+    }
     if request_order.scopes.is_empty() {
         errors.push("request order is missing a scope".to_string());
     }
+    if request_order.get_required_validator_count() == 0 {
+        errors.push("request order must require at least one validator".to_string());
+    }
+    if let Some(allowed_validators) = request_order.maybe_get_allowed_validators() {
+        let distinct_validators = allowed_validators.iter().collect::<HashSet<_>>().len();
+        if (request_order.get_required_validator_count() as usize) > distinct_validators {
+            errors.push(format!(
+                "request order requires [{}] validators but only allows [{}]",
+                request_order.get_required_validator_count(),
+                distinct_validators
+            ));
+        }
+    }
+    errors.extend(validate_access_routes(
+        request_order
+            .get_access_definitions()
+            .iter()
+            .flat_map(|definition| definition.get_access_routes()),
+    ));
     if !errors.is_empty() {
         ContractError::InvalidRequest {
             message: errors.join(", "),
@@ -69,3 +153,22 @@ pub fn validate_request_order(request_order: &ValidationRequestOrder) -> Result<
         Ok(())
     }
 }
+
+/// Checks that each of a collection of access routes has a non-blank route, returning
+/// a description of each one which does not.
+///
+/// # Parameters
+///
+/// * `access_routes` The access routes to check.
+pub fn validate_access_routes<'a, I: IntoIterator<Item = &'a AccessRoute>>(
+    access_routes: I,
+) -> Vec<String> {
+    access_routes
+        .into_iter()
+        .filter(|access_route| access_route.get_route().trim().is_empty())
+        .map(|access_route| match access_route.maybe_get_name() {
+            Some(name) => format!("access route [{}] is missing a route", name),
+            None => "access route is missing a route".to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,370 @@
+use std::collections::BTreeMap;
+
+use super::aliases::ContractResult;
+use crate::types::{
+    core::error::ContractError, request::validation_request::ValidationRequestOrder,
+    validation_cost::ValidationCost,
+};
+
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Decimal, MessageInfo, Uint128};
+use provwasm_std::ProvenanceMsg;
+use result_extensions::ResultExtensions;
+
+/// Combines a list of coins into a list with at most one coin per denomination, sorted by
+/// denomination and omitting any zero amounts, as is required of the coins sent in a [BankMsg].
+///
+/// # Parameters
+///
+/// * `coins` The coins to combine.
+pub fn normalize_coins(coins: &[Coin]) -> Vec<Coin> {
+    let mut totals: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for coin in coins {
+        let total = totals.entry(coin.denom.as_str()).or_default();
+        *total = total.saturating_add(coin.amount);
+    }
+    totals
+        .into_iter()
+        .filter(|(_, amount)| !amount.is_zero())
+        .map(|(denom, amount)| Coin::new(amount.u128(), denom))
+        .collect()
+}
+
+/// Subtracts one list of coins from another on a per-denomination basis, returning the
+/// [normalized](normalize_coins) remainder. Denominations for which the subtrahend is
+/// greater than or equal to the minuend are omitted from the result.
+///
+/// # Parameters
+///
+/// * `minuend` The coins to subtract from.
+/// * `subtrahend` The coins to subtract.
+pub fn subtract_coins(minuend: &[Coin], subtrahend: &[Coin]) -> Vec<Coin> {
+    let subtrahend = normalize_coins(subtrahend);
+    normalize_coins(minuend)
+        .into_iter()
+        .map(|coin| {
+            let deduction = subtrahend
+                .iter()
+                .find(|other| other.denom == coin.denom)
+                .map(|other| other.amount)
+                .unwrap_or_default();
+            Coin {
+                amount: coin.amount.saturating_sub(deduction),
+                denom: coin.denom,
+            }
+        })
+        .filter(|coin| !coin.amount.is_zero())
+        .collect()
+}
+
+/// Multiplies each of a list of coins by a fraction, rounding down, and returns the
+/// [normalized](normalize_coins) result.
+///
+/// # Parameters
+///
+/// * `coins` The coins to multiply.
+/// * `fraction` The fraction to multiply each coin's amount by.
+pub fn multiply_coins(coins: &[Coin], fraction: Decimal) -> Vec<Coin> {
+    normalize_coins(
+        &coins
+            .iter()
+            .map(|coin| Coin {
+                amount: coin.amount * fraction,
+                denom: coin.denom.clone(),
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Divides each of a list of coins into a number of equal shares, rounding down, and returns
+/// a single [normalized](normalize_coins) share.
+///
+/// # Parameters
+///
+/// * `coins` The coins to divide.
+/// * `shares` The number of shares to divide the coins into, which must be greater than zero.
+pub fn divide_coins(coins: &[Coin], shares: u32) -> Vec<Coin> {
+    normalize_coins(
+        &coins
+            .iter()
+            .map(|coin| Coin {
+                amount: coin.amount / Uint128::from(shares),
+                denom: coin.denom.clone(),
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Ensures that the funds provided to a route are exactly equal to an expected amount,
+/// regardless of the order or grouping of the coins.
+///
+/// # Parameters
+///
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+///   of the message, as well as the funds provided as an amount during the transaction.
+/// * `expected` The coins which are required to be provided.
+/// * `purpose` A description of what the funds are required for.
+pub fn check_funds_match(
+    info: &MessageInfo,
+    expected: &[Coin],
+    purpose: &str,
+) -> ContractResult<()> {
+    let provided = normalize_coins(&info.funds);
+    let expected = normalize_coins(expected);
+    if provided != expected {
+        ContractError::InvalidFunds {
+            message: format!(
+                "{} requires funds of exactly [{}] but [{}] were provided",
+                purpose,
+                display_coins(&expected),
+                display_coins(&provided),
+            ),
+        }
+        .to_err()
+    } else {
+        Ok(())
+    }
+}
+
+/// Generates a message sending coins held in escrow by the contract to a recipient,
+/// or [None] if there is nothing to send.
+///
+/// # Parameters
+///
+/// * `recipient` The bech32 address which should receive the coins.
+/// * `coins` The coins to send.
+pub fn generate_payment_msg(recipient: &Addr, coins: &[Coin]) -> Option<CosmosMsg<ProvenanceMsg>> {
+    let amount = normalize_coins(coins);
+    if amount.is_empty() {
+        None
+    } else {
+        Some(
+            BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount,
+            }
+            .into(),
+        )
+    }
+}
+
+/// Generates the messages paying out a fulfilled validation request's escrowed quote. Each
+/// [ValidationCost] is paid to its destination, for as much as remains of the quote, and
+/// whatever remains of the quote afterwards is paid to the validator.
+///
+/// # Parameters
+///
+/// * `validator` The bech32 address of the validator which fulfilled the request.
+/// * `quote` The coins held in escrow for the request.
+/// * `validation_costs` The costs agreed upon when the validator accepted the request.
+pub fn generate_validation_payment_msgs(
+    validator: &Addr,
+    quote: &[Coin],
+    validation_costs: &[ValidationCost],
+) -> Vec<CosmosMsg<ProvenanceMsg>> {
+    let mut remainder = normalize_coins(quote);
+    let mut msgs = vec![];
+    for cost in validation_costs {
+        let available = remainder
+            .iter()
+            .find(|coin| coin.denom == cost.denom)
+            .map(|coin| coin.amount)
+            .unwrap_or_default();
+        let payment = Coin {
+            amount: cost.amount.min(available),
+            denom: cost.denom.clone(),
+        };
+        remainder = subtract_coins(&remainder, std::slice::from_ref(&payment));
+        msgs.extend(generate_payment_msg(&cost.destination.address, &[payment]));
+    }
+    msgs.extend(generate_payment_msg(validator, &remainder));
+    msgs
+}
+
+/// Generates the messages settling a fulfilled validation request's escrowed quote. The given
+/// payout is split evenly between the validators recorded in the request's settlement, each
+/// of whose share is [paid out](generate_validation_payment_msgs) along with its costs, and
+/// whatever remains of the quote is refunded to the owner of the request.
+///
+/// # Parameters
+///
+/// * `request` The fulfilled validation request to settle.
+/// * `payout` The portion of the request's quote which should be paid to its validators.
+pub fn generate_settlement_msgs(
+    request: &ValidationRequestOrder,
+    payout: &[Coin],
+) -> Vec<CosmosMsg<ProvenanceMsg>> {
+    let validators = request
+        .maybe_get_settlement()
+        .map(|settlement| settlement.get_validators())
+        .unwrap_or_default();
+    let share = match validators.len() as u32 {
+        0 => vec![],
+        validator_count => divide_coins(payout, validator_count),
+    };
+    let mut msgs = vec![];
+    let mut paid = vec![];
+    for validator in validators {
+        msgs.extend(generate_validation_payment_msgs(
+            validator,
+            &share,
+            request
+                .maybe_get_claim(validator)
+                .map(|claim| claim.get_validation_costs())
+                .unwrap_or_default(),
+        ));
+        paid.extend(share.iter().cloned());
+    }
+    msgs.extend(generate_payment_msg(
+        &request.owner,
+        &subtract_coins(request.get_quote(), &paid),
+    ));
+    msgs
+}
+
+/// Generates a displayable list of coins.
+///
+/// # Parameters
+///
+/// * `coins` The coins to display.
+pub fn display_coins(coins: &[Coin]) -> String {
+    coins
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        check_funds_match, divide_coins, generate_payment_msg, generate_validation_payment_msgs,
+        multiply_coins, normalize_coins, subtract_coins,
+    };
+    use crate::{
+        test::arbitrary::{arb_addr, arb_quote},
+        types::{entity::EntityDetail, validation_cost::ValidationCost},
+    };
+
+    use cosmwasm_std::{coin, testing::mock_info, Addr, BankMsg, CosmosMsg, Decimal, Uint128};
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    #[test]
+    fn normalize_coins_combines_and_sorts_denominations() {
+        assert_eq!(
+            vec![coin(3, "a"), coin(7, "b")],
+            normalize_coins(&[coin(5, "b"), coin(3, "a"), coin(0, "c"), coin(2, "b")]),
+        );
+    }
+
+    #[test]
+    fn subtract_coins_omits_exhausted_denominations() {
+        assert_eq!(
+            vec![coin(4, "b"), coin(1, "c")],
+            subtract_coins(
+                &[coin(3, "a"), coin(5, "b"), coin(1, "c")],
+                &[coin(4, "a"), coin(1, "b")]
+            ),
+        );
+    }
+
+    #[test]
+    fn multiply_coins_rounds_down() {
+        assert_eq!(
+            vec![coin(3, "a"), coin(1, "b")],
+            multiply_coins(
+                &[coin(7, "a"), coin(3, "b"), coin(1, "c")],
+                Decimal::percent(50)
+            ),
+        );
+    }
+
+    #[test]
+    fn divide_coins_rounds_down() {
+        assert_eq!(
+            vec![coin(2, "a"), coin(1, "b")],
+            divide_coins(&[coin(7, "a"), coin(3, "b"), coin(2, "c")], 3),
+        );
+    }
+
+    #[test]
+    fn validation_payment_pays_costs_and_remainder_to_validator() {
+        let validator = Addr::unchecked("validator");
+        let destination = Addr::unchecked("destination");
+        let cost = ValidationCost {
+            amount: Uint128::new(3),
+            denom: "a".to_string(),
+            destination: EntityDetail {
+                address: destination.clone(),
+                name: None,
+                description: None,
+                home_url: None,
+                source_url: None,
+            },
+        };
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: destination.to_string(),
+                    amount: vec![coin(3, "a")],
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: validator.to_string(),
+                    amount: vec![coin(2, "a"), coin(4, "b")],
+                }),
+            ],
+            generate_validation_payment_msgs(&validator, &[coin(5, "a"), coin(4, "b")], &[cost]),
+        );
+    }
+
+    #[test]
+    fn validation_payment_caps_costs_at_quote() {
+        let validator = Addr::unchecked("validator");
+        let destination = Addr::unchecked("destination");
+        let cost = ValidationCost {
+            amount: Uint128::new(7),
+            denom: "a".to_string(),
+            destination: EntityDetail {
+                address: destination.clone(),
+                name: None,
+                description: None,
+                home_url: None,
+                source_url: None,
+            },
+        };
+        assert_eq!(
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: destination.to_string(),
+                    amount: vec![coin(5, "a")],
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: validator.to_string(),
+                    amount: vec![coin(4, "b")],
+                }),
+            ],
+            generate_validation_payment_msgs(&validator, &[coin(5, "a"), coin(4, "b")], &[cost]),
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn funds_match_any_ordering_of_quote(quote in arb_quote(), sender in arb_addr()) {
+            let mut funds = quote.clone();
+            funds.reverse();
+            prop_assert!(check_funds_match(&mock_info(sender.as_str(), &funds), &quote, "test").is_ok());
+            prop_assert_eq!(quote.is_empty(), generate_payment_msg(&sender, &quote).is_none());
+        }
+
+        #[test]
+        fn payment_msg_sends_normalized_quote(quote in arb_quote(), recipient in arb_addr()) {
+            match generate_payment_msg(&recipient, &quote) {
+                Some(CosmosMsg::Bank(BankMsg::Send { to_address, amount })) => {
+                    prop_assert_eq!(recipient.to_string(), to_address);
+                    prop_assert_eq!(normalize_coins(&quote), amount);
+                }
+                None => prop_assert!(normalize_coins(&quote).is_empty()),
+                other => prop_assert!(false, "unexpected payment message: {:?}", other),
+            }
+        }
+    }
+}
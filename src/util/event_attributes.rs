@@ -37,6 +37,9 @@ pub enum EventType {
     /// [update a validator configuration](crate::execute::validator_configuration::update_existing_validator_configuration).
     UpdateValidatorConfiguration,
     /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [delete a validator configuration](crate::execute::validator_configuration::delete_validator_configuration).
+    DeleteValidatorConfiguration,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
     /// [create a validation request](crate::execute::validation_request::create_request_for_validation).
     AddValidationRequest,
     /// Occurs when the contract is [executed](crate::contract::execute) to
@@ -46,6 +49,30 @@ pub enum EventType {
     /// [delete a validation request](crate::execute::validation_request::delete_request_for_validation).
     DeleteValidationRequest,
     /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [cancel a validation request](crate::execute::validation_request::cancel_request_for_validation).
+    CancelValidationRequest,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [accept a validation request](crate::execute::validation_request::accept_request_for_validation).
+    AcceptValidationRequest,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [release a validation request](crate::execute::validation_request::release_request_for_validation).
+    ReleaseValidationRequest,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [expire validation requests](crate::execute::validation_request::expire_requests_for_validation).
+    ExpireValidationRequests,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [submit validation results](crate::execute::validation_result::submit_validation_results).
+    SubmitValidationResults,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [dispute validation results](crate::execute::validation_result::dispute_validation_result).
+    DisputeValidationResult,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [resolve a dispute](crate::execute::validation_result::resolve_dispute).
+    ResolveDispute,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
+    /// [settle a validation request](crate::execute::validation_result::settle_validation_request).
+    SettleValidationRequest,
+    /// Occurs when the contract is [executed](crate::contract::execute) to
     /// [update its settings](crate::execute::update_settings::update_settings).
     UpdateSettings,
 }
@@ -63,9 +90,18 @@ impl Into<String> for EventType {
             EventType::DeleteValidationDefinition => "delete_validation_definition",
             EventType::AddValidatorConfiguration => "add_validator_configuration",
             EventType::UpdateValidatorConfiguration => "update_validator_configuration",
+            EventType::DeleteValidatorConfiguration => "delete_validator_configuration",
             EventType::AddValidationRequest => "create_validation_request",
             EventType::UpdateValidationRequest => "update_validation_request",
             EventType::DeleteValidationRequest => "delete_validation_request",
+            EventType::CancelValidationRequest => "cancel_validation_request",
+            EventType::AcceptValidationRequest => "accept_validation_request",
+            EventType::ReleaseValidationRequest => "release_validation_request",
+            EventType::ExpireValidationRequests => "expire_validation_requests",
+            EventType::SubmitValidationResults => "submit_validation_results",
+            EventType::DisputeValidationResult => "dispute_validation_result",
+            EventType::ResolveDispute => "resolve_dispute",
+            EventType::SettleValidationRequest => "settle_validation_request",
             EventType::UpdateSettings => "update_settings",
         }
         .into()
@@ -90,8 +126,8 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `event_type` All events should denote their type for external consumers of Provenance
-    /// Blockchain Event Stream, so this value is required for any new instance and appends the
-    /// name of the event with the key of [EVENT_TYPE_KEY](super::constants::EVENT_TYPE_KEY).
+    ///   Blockchain Event Stream, so this value is required for any new instance and appends the
+    ///   name of the event with the key of [EVENT_TYPE_KEY](super::constants::EVENT_TYPE_KEY).
     pub fn new(event_type: EventType) -> Self {
         EventAttributes {
             attributes: vec![(EVENT_TYPE_KEY.into(), event_type.into())],
@@ -104,7 +140,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `asset_type` An enumerated value for the type of the asset(s) targeted for validation that are
-    /// associated with the event, keyed to [ASSET_TYPE_KEY](super::constants::ASSET_TYPE_KEY).
+    ///   associated with the event, keyed to [ASSET_TYPE_KEY](super::constants::ASSET_TYPE_KEY).
     pub fn set_asset_type<T: Into<String>>(mut self, asset_type: T) -> Self {
         self.attributes
             .push((ASSET_TYPE_KEY.to_string(), asset_type.into()));
@@ -117,7 +153,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `validation_request_id` An ID for a validation request associated with the event,
-    /// keyed to [VALIDATION_REQUEST_ID_KEY](super::constants::VALIDATION_REQUEST_ID_KEY).
+    ///   keyed to [VALIDATION_REQUEST_ID_KEY](super::constants::VALIDATION_REQUEST_ID_KEY).
     pub fn set_validation_request_id<T: Into<String>>(mut self, validation_request_id: T) -> Self {
         self.attributes.push((
             VALIDATION_REQUEST_ID_KEY.to_string(),
@@ -132,7 +168,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `validation_status` A status for a validation request associated with the event,
-    /// keyed to [VALIDATION_STATUS_KEY](super::constants::VALIDATION_STATUS_KEY).
+    ///   keyed to [VALIDATION_STATUS_KEY](super::constants::VALIDATION_STATUS_KEY).
     pub fn set_validation_status<T: Into<String>>(mut self, validation_status: T) -> Self {
         self.attributes
             .push((VALIDATION_STATUS_KEY.to_string(), validation_status.into()));
@@ -145,7 +181,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `validation_type` An enumerated value for the type of validation associated with the event,
-    /// keyed to [VALIDATION_TYPE_KEY](super::constants::VALIDATION_TYPE_KEY).
+    ///   keyed to [VALIDATION_TYPE_KEY](super::constants::VALIDATION_TYPE_KEY).
     pub fn set_validation_type<T: Into<String>>(mut self, validation_type: T) -> Self {
         self.attributes
             .push((VALIDATION_TYPE_KEY.to_string(), validation_type.into()));
@@ -158,7 +194,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `scope_address` The bech32 address for the validation results scope associated with the event, keyed to
-    /// [RESULTS_SCOPE_ADDRESS_KEY](super::constants::RESULTS_SCOPE_ADDRESS_KEY).
+    ///   [RESULTS_SCOPE_ADDRESS_KEY](super::constants::RESULTS_SCOPE_ADDRESS_KEY).
     pub fn set_results_scope_address<T: Into<String>>(mut self, scope_address: T) -> Self {
         self.attributes
             .push((RESULTS_SCOPE_ADDRESS_KEY.to_string(), scope_address.into()));
@@ -172,7 +208,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `validator_address` The bech32 address for the validator associated with the event, keyed to
-    /// [VALIDATOR_ADDRESS_KEY](super::constants::VALIDATOR_ADDRESS_KEY).
+    ///   [VALIDATOR_ADDRESS_KEY](super::constants::VALIDATOR_ADDRESS_KEY).
     pub fn set_validator<T: Into<String>>(mut self, validator_address: T) -> Self {
         self.attributes
             .push((VALIDATOR_ADDRESS_KEY.to_string(), validator_address.into()));
@@ -185,7 +221,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `addresses` A collection of addresses of entities associated with the event,
-    /// keyed to [ENTITY_ADDRESSES_KEY](super::constants::ENTITY_ADDRESSES_KEY).
+    ///   keyed to [ENTITY_ADDRESSES_KEY](super::constants::ENTITY_ADDRESSES_KEY).
     pub fn set_entity_addresses(mut self, addresses: &[String]) -> Self {
         self.attributes
             .push((ENTITY_ADDRESSES_KEY.to_string(), addresses.join(", ")));
@@ -198,7 +234,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `new_value` Any dynamic value that pertains to the current execution process, using the
-    /// key [NEW_VALUE_KEY](super::constants::NEW_VALUE_KEY).
+    ///   key [NEW_VALUE_KEY](super::constants::NEW_VALUE_KEY).
     pub fn set_new_value<T: ToString>(mut self, new_value: T) -> Self {
         self.attributes
             .push((NEW_VALUE_KEY.to_string(), new_value.to_string()));
@@ -227,7 +263,7 @@ impl EventAttributes {
     /// # Parameters
     ///
     /// * `additional_metadata` An instance of additional metadata to be displayed to any external
-    /// consumers.  Uses the key of [ADDITIONAL_METADATA_KEY](super::constants::ADDITIONAL_METADATA_KEY).
+    ///   consumers.  Uses the key of [ADDITIONAL_METADATA_KEY](super::constants::ADDITIONAL_METADATA_KEY).
     pub fn set_additional_metadata(
         mut self,
         additional_metadata: &EventAdditionalMetadata,
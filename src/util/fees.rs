@@ -1,58 +1,119 @@
-use super::aliases::DepsC;
-use crate::storage::contract_info::{get_contract_info, ContractInfo};
+use std::collections::HashSet;
+
+use super::aliases::{ContractResult, DepsC};
+use super::constants::{CONTRACT_FEE_CHARGED_KEY, REQUEST_CREATION_FEE_CHARGED_KEY};
+use crate::storage::contract_info::get_contract_info;
 use crate::types::core::error::ContractError;
-use crate::util::constants::NHASH;
+use crate::types::fee::{ContractFee, FeeOperation};
 
-use cosmwasm_std::{coin, Addr, CosmosMsg};
+use cosmwasm_std::{coin, Addr, Api, CosmosMsg, Response};
 use provwasm_std::{assess_custom_fee, MsgFeesMsgParams, ProvenanceMsg, ProvenanceMsgParams};
 use result_extensions::ResultExtensions;
 
-/// Generates a fee paid to the contract as payment for usage of the contract.
-/// The fee is sent to the contract's admin address so that the admin is funded
-/// for the purposes of making their own requests to the contract.
+/// Generates a fee paid to the contract as payment for usage of the contract, as configured for an operation
+/// in the contract's [fee schedule](crate::storage::contract_info::ContractInfo::fee_schedule). The fee is
+/// sent to the recipient of the fee, or otherwise to the contract's admin address so that the admin is
+/// funded for the purposes of making their own requests to the contract.
 ///
 /// # Parameters
 ///
-/// * `fee_type` A string description of the fee being charged
+/// * `operation` The operation for which the fee is being charged.
 /// * `deps` An immutable dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like the contract's internal storage and a querier to retrieve blockchain objects.
+///   resources like the contract's internal storage and a querier to retrieve blockchain objects.
 /// * `contract_addr` The bech32 Provenance address of the contract iself, to facilitate the
-/// contract charging the fee to the sender of a request.
-/// * `fee_calculation` A function to calculate the [nhash](NHASH) fee to charge the request
-/// sender given the stored [ContractInfo].
-pub fn generate_contract_fee_msg<S: Into<String>, F: Fn(&ContractInfo) -> u128>(
-    fee_type: S,
+///   contract charging the fee to the sender of a request.
+pub fn generate_contract_fee_msg(
+    operation: FeeOperation,
     deps: &DepsC,
     contract_addr: Addr,
-    fee_calculation: F,
 ) -> Result<Option<CosmosMsg<ProvenanceMsg>>, ContractError> {
-    let fee_type = fee_type.into();
     let contract_info = get_contract_info(deps.storage)?;
-    let nhash_fee_amount = fee_calculation(&contract_info);
-    // Only dispatch a fee message if the fee amount is greater than zero. Charging a fee of zero
-    // means nothing
-    if nhash_fee_amount > 0 {
-        Some(assess_custom_fee(
-            // Provenance Blockchain fees are required to be sent as either usd or nhash.  This
-            // contract only supports nhash for now
-            coin(nhash_fee_amount, NHASH),
+    match contract_info.maybe_get_fee(operation) {
+        // Only dispatch a fee message if the fee amount is greater than zero. Charging a fee of zero
+        // means nothing
+        Some(fee) if !fee.amount.is_zero() => Some(assess_custom_fee(
+            // Provenance Blockchain fees are required to be sent as either usd or nhash
+            coin(fee.amount.u128(), fee.denom.to_string()),
             // Specify a somewhat descriptive message to ensure that signers using the Provenance
             // Blockchain wallet can understand the reason for the fee
-            Some(format!("{} {} fee", &fee_type, NHASH)),
+            Some(format!("{} {} fee", operation.get_description(), fee.denom)),
             // The contract's address must be used as the "from" value.  This does not mean that
             // the contract sends the fee, but it is required for the contract to sign and dispatch
             // the message that will charge the request sender the fee
             contract_addr,
-            // Always send fees charged to the admin address.  This ensures that the admin is
-            // always funded in order to make future requests
-            Some(contract_info.admin),
-        )?)
-    } else {
-        None
+            // Send the fee to its configured recipient, falling back to the admin address.  This
+            // ensures that the admin is funded in order to make future requests
+            Some(
+                fee.maybe_get_recipient()
+                    .cloned()
+                    .unwrap_or_else(|| contract_info.admin.clone()),
+            ),
+        )?),
+        _ => None,
+    }
+    .to_ok()
+}
+
+/// Adds a fee message generated by [generate_contract_fee_msg], if any, to a response, along with
+/// an attribute displaying the amount of the fee charged. The fee for creating a validation request
+/// is also displayed under [REQUEST_CREATION_FEE_CHARGED_KEY].
+///
+/// # Parameters
+///
+/// * `response` The response to add the fee message to.
+/// * `operation` The operation for which the fee is being charged.
+/// * `fee_msg` The fee message to add, if a fee is charged.
+pub fn add_contract_fee_msg(
+    response: Response<ProvenanceMsg>,
+    operation: FeeOperation,
+    fee_msg: Option<CosmosMsg<ProvenanceMsg>>,
+) -> ContractResult<Response<ProvenanceMsg>> {
+    match fee_msg {
+        Some(fee_msg) => {
+            let fee_amount_display = get_custom_fee_amount_display(&fee_msg)?;
+            let response = if operation == FeeOperation::CreateRequest {
+                response.add_attribute(REQUEST_CREATION_FEE_CHARGED_KEY, &fee_amount_display)
+            } else {
+                response
+            };
+            response
+                .add_attribute(CONTRACT_FEE_CHARGED_KEY, fee_amount_display)
+                .add_message(fee_msg)
+        }
+        None => response,
     }
     .to_ok()
 }
 
+/// Checks that a fee schedule has at most one fee for each operation and that the recipient of each
+/// fee is a valid address, returning a description of each problem found.
+///
+/// # Parameters
+///
+/// * `api` The cosmwasm api, used to validate the recipients of the fees.
+/// * `fee_schedule` The fee schedule to check.
+pub fn validate_fee_schedule(api: &dyn Api, fee_schedule: &[ContractFee]) -> Vec<String> {
+    let mut errors = vec![];
+    let mut operations = HashSet::new();
+    for fee in fee_schedule {
+        if !operations.insert(fee.operation) {
+            errors.push(format!(
+                "fee schedule contains more than one fee for operation [{}]",
+                fee.operation
+            ));
+        }
+        if let Some(recipient) = fee.maybe_get_recipient() {
+            if api.addr_validate(recipient.as_str()).is_err() {
+                errors.push(format!(
+                    "recipient [{}] of the fee for operation [{}] was invalid",
+                    recipient, fee.operation
+                ));
+            }
+        }
+    }
+    errors
+}
+
 /// Generates a displayable quote of a fee being charged.
 ///
 /// # Parameters
@@ -76,4 +137,135 @@ pub fn get_custom_fee_amount_display(
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::{
+        add_contract_fee_msg, generate_contract_fee_msg, get_custom_fee_amount_display,
+        validate_fee_schedule,
+    };
+    use crate::{
+        storage::contract_info::{get_contract_info, set_contract_info},
+        test::helpers::{set_up_contract_info, single_attribute_for_key, TEST_ADMIN},
+        types::fee::{ContractFee, FeeDenom, FeeOperation},
+        util::constants::{CONTRACT_FEE_CHARGED_KEY, REQUEST_CREATION_FEE_CHARGED_KEY},
+    };
+
+    use cosmwasm_std::{coin, testing::mock_env, Addr, Response, Uint128};
+    use provwasm_mocks::mock_dependencies;
+    use provwasm_std::assess_custom_fee;
+
+    fn fee(operation: FeeOperation, amount: u128, recipient: Option<&str>) -> ContractFee {
+        ContractFee {
+            operation,
+            amount: Uint128::new(amount),
+            denom: FeeDenom::Usd,
+            recipient: recipient.map(Addr::unchecked),
+        }
+    }
+
+    #[test]
+    fn contract_fee_msg_is_generated_from_fee_schedule() {
+        let mut deps = mock_dependencies(&[]);
+        set_up_contract_info(&mut deps);
+        let mut contract_info = get_contract_info(&deps.storage).unwrap();
+        contract_info.fee_schedule = vec![
+            fee(FeeOperation::CreateRequest, 100, None),
+            fee(FeeOperation::SubmitResults, 200, Some("feerecipient")),
+            fee(FeeOperation::AcceptRequest, 0, None),
+        ];
+        set_contract_info(deps.as_mut().storage, &contract_info).unwrap();
+        let contract_address = mock_env().contract.address;
+        let generate = |operation| {
+            generate_contract_fee_msg(operation, &deps.as_ref(), contract_address.clone()).unwrap()
+        };
+
+        let create_request_fee_msg = generate(FeeOperation::CreateRequest).unwrap();
+        assert_eq!(
+            assess_custom_fee(
+                coin(100, "usd"),
+                Some("validation request creation usd fee".to_string()),
+                contract_address.clone(),
+                Some(Addr::unchecked(TEST_ADMIN)),
+            )
+            .unwrap(),
+            create_request_fee_msg
+        );
+        assert_eq!(
+            "100usd",
+            get_custom_fee_amount_display(&create_request_fee_msg).unwrap()
+        );
+        assert_eq!(
+            assess_custom_fee(
+                coin(200, "usd"),
+                Some("validation result submission usd fee".to_string()),
+                contract_address.clone(),
+                Some(Addr::unchecked("feerecipient")),
+            )
+            .unwrap(),
+            generate(FeeOperation::SubmitResults).unwrap()
+        );
+        assert_eq!(None, generate(FeeOperation::AcceptRequest));
+        assert_eq!(None, generate(FeeOperation::UpdateRequest));
+    }
+
+    #[test]
+    fn request_creation_fee_keeps_its_own_attribute() {
+        let contract_address = mock_env().contract.address;
+        let fee_msg = assess_custom_fee(
+            coin(100, "usd"),
+            Some("validation request creation usd fee".to_string()),
+            contract_address,
+            Some(Addr::unchecked(TEST_ADMIN)),
+        )
+        .unwrap();
+
+        let create_response = add_contract_fee_msg(
+            Response::new(),
+            FeeOperation::CreateRequest,
+            Some(fee_msg.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            "100usd",
+            single_attribute_for_key(&create_response, REQUEST_CREATION_FEE_CHARGED_KEY)
+        );
+        assert_eq!(
+            "100usd",
+            single_attribute_for_key(&create_response, CONTRACT_FEE_CHARGED_KEY)
+        );
+
+        let update_response =
+            add_contract_fee_msg(Response::new(), FeeOperation::UpdateRequest, Some(fee_msg))
+                .unwrap();
+        assert!(update_response
+            .attributes
+            .iter()
+            .all(|attribute| attribute.key != REQUEST_CREATION_FEE_CHARGED_KEY));
+        assert_eq!(
+            "100usd",
+            single_attribute_for_key(&update_response, CONTRACT_FEE_CHARGED_KEY)
+        );
+    }
+
+    #[test]
+    fn fee_schedule_rejects_duplicate_operations() {
+        let deps = mock_dependencies(&[]);
+        assert!(validate_fee_schedule(
+            &deps.api,
+            &[
+                fee(FeeOperation::CreateRequest, 1, None),
+                fee(FeeOperation::UpdateRequest, 1, Some("feerecipient")),
+            ]
+        )
+        .is_empty());
+        assert_eq!(
+            vec!["fee schedule contains more than one fee for operation [create_request]"],
+            validate_fee_schedule(
+                &deps.api,
+                &[
+                    fee(FeeOperation::CreateRequest, 1, None),
+                    fee(FeeOperation::CreateRequest, 2, None),
+                ]
+            )
+        );
+    }
+}
@@ -1,18 +1,29 @@
 use super::{
     aliases::{ContractResult, DepsC},
+    escrow::{display_coins, normalize_coins},
     event_attributes::EventAdditionalMetadata,
+    fees::validate_fee_schedule,
+    functions::generate_validation_definition_attribute_name,
 };
 use crate::{
-    storage::contract_info::{get_contract_info, ContractInfo},
+    storage::{
+        contract_info::{get_contract_info, ContractInfo},
+        validation_definition::{get_bound_validation_definitions, store_validation_definition},
+    },
     types::{
-        core::error::ContractError, entity::EntityDetail,
-        request::validation_request::ValidationRequestOrder,
+        access_definition::AccessDefinition,
+        core::error::ContractError,
+        entity::EntityDetail,
+        expiration::Duration,
+        fee::ContractFee,
+        request::{settings_update::SettingsUpdate, validation_request::ValidationRequestOrder},
         validation_definition::ValidationDefinition,
         validator_configuration::ValidatorConfiguration,
     },
 };
 
-use cosmwasm_std::MessageInfo;
+use cosmwasm_std::{Addr, Api, CosmosMsg, MessageInfo, Storage};
+use provwasm_std::{bind_name, unbind_name, NameBinding, ProvenanceMsg};
 use result_extensions::ResultExtensions;
 
 /// Ensures that only the admin of the contract can call into a route.
@@ -20,9 +31,9 @@ use result_extensions::ResultExtensions;
 /// # Parameters
 ///
 /// * `deps` An immutable dependencies object provided by the cosmwasm framework.  Allows access to useful
-/// resources like the contract's internal storage and a querier to retrieve blockchain objects.
+///   resources like the contract's internal storage and a querier to retrieve blockchain objects.
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 ///
 /// # Example
 /// ```
@@ -38,7 +49,7 @@ use result_extensions::ResultExtensions;
 ///         Addr::unchecked("admin-name"),
 ///         "bind name".to_string(),
 ///         "contract name".to_string(),
-///         None,
+///         vec![],
 ///     )
 /// ).expect("expected contract info to save successfully");
 /// let info = mock_info("admin-name", &[]);
@@ -61,7 +72,7 @@ pub fn check_admin_only(deps: &DepsC, info: &MessageInfo) -> ContractResult<()>
 /// # Parameters
 ///
 /// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
-/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+///   of the instantiation message, as well as the funds provided as an amount during the transaction.
 ///
 /// # Example
 /// ```
@@ -195,6 +206,26 @@ pub fn get_validation_definition_update(
         (true, true) => {}
         (false, false) => {}
     }
+    if old.cancellation_fraction != new.cancellation_fraction {
+        changes.add_metadata(
+            "old_cancellation_fraction",
+            old.get_cancellation_fraction().to_string(),
+        );
+        changes.add_metadata(
+            "new_cancellation_fraction",
+            new.get_cancellation_fraction().to_string(),
+        );
+    }
+    if old.dispute_period != new.dispute_period {
+        changes.add_metadata(
+            "old_dispute_period",
+            display_duration(old.maybe_get_dispute_period()),
+        );
+        changes.add_metadata(
+            "new_dispute_period",
+            display_duration(new.maybe_get_dispute_period()),
+        );
+    }
     changes
 }
 
@@ -244,7 +275,38 @@ pub fn get_validation_request_update(
         changes.add_metadata("old_owner", old.owner.to_string());
         changes.add_metadata("new_owner", new.owner.to_string());
     }
-    // TODO: Determine best way to check for changes in array fields
+    if old.validation_type != new.validation_type {
+        changes.add_metadata("old_validation_type", old.validation_type.to_string());
+        changes.add_metadata("new_validation_type", new.validation_type.to_string());
+    }
+    if old.scopes != new.scopes {
+        changes.add_metadata("old_scopes", display_addresses(old.get_scopes()));
+        changes.add_metadata("new_scopes", display_addresses(new.get_scopes()));
+    }
+    if old.allowed_validators != new.allowed_validators {
+        changes.add_metadata(
+            "old_allowed_validators",
+            display_allowed_validators(old.maybe_get_allowed_validators()),
+        );
+        changes.add_metadata(
+            "new_allowed_validators",
+            display_allowed_validators(new.maybe_get_allowed_validators()),
+        );
+    }
+    if normalize_coins(old.get_quote()) != normalize_coins(new.get_quote()) {
+        changes.add_metadata("old_quote", display_coins(old.get_quote()));
+        changes.add_metadata("new_quote", display_coins(new.get_quote()));
+    }
+    if old.access_definitions != new.access_definitions {
+        changes.add_metadata(
+            "old_access_routes",
+            display_access_routes(old.get_access_definitions()),
+        );
+        changes.add_metadata(
+            "new_access_routes",
+            display_access_routes(new.get_access_definitions()),
+        );
+    }
     if old.status != new.status {
         changes.add_metadata("old_status", old.status.to_string());
         changes.add_metadata("new_status", new.status.to_string());
@@ -252,7 +314,80 @@ pub fn get_validation_request_update(
     changes
 }
 
-// TODO: Remove below function if the migration entrypoint can't use it
+/// Generates a displayable list of addresses.
+///
+/// # Parameters
+///
+/// * `addresses` The addresses to display.
+fn display_addresses(addresses: &[Addr]) -> String {
+    addresses
+        .iter()
+        .map(|address| address.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Generates a displayable list of the allowed validators of a validation request,
+/// which is "any" when the request does not restrict its validators.
+///
+/// # Parameters
+///
+/// * `allowed_validators` The allowed validators of a validation request, if any.
+fn display_allowed_validators(allowed_validators: Option<&[Addr]>) -> String {
+    match allowed_validators {
+        Some(allowed_validators) => display_addresses(allowed_validators),
+        None => "any".to_string(),
+    }
+}
+
+/// Generates a displayable list of the routes of a collection of access definitions,
+/// which is "none" when there are no routes.
+///
+/// # Parameters
+///
+/// * `access_definitions` The access definitions whose routes should be displayed.
+fn display_access_routes(access_definitions: &[AccessDefinition]) -> String {
+    let routes = access_definitions
+        .iter()
+        .flat_map(|definition| definition.get_access_routes())
+        .map(|access_route| access_route.get_route())
+        .collect::<Vec<_>>();
+    if routes.is_empty() {
+        "none".to_string()
+    } else {
+        routes.join(", ")
+    }
+}
+
+/// Generates a displayable list of the fees of a fee schedule, which is "none" when there are no fees.
+///
+/// # Parameters
+///
+/// * `fee_schedule` The fees which should be displayed.
+fn display_fee_schedule(fee_schedule: &[ContractFee]) -> String {
+    if fee_schedule.is_empty() {
+        "none".to_string()
+    } else {
+        fee_schedule
+            .iter()
+            .map(|fee| fee.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Generates a displayable span of time, which is "none" when no span is given.
+///
+/// # Parameters
+///
+/// * `duration` A span of time, if any.
+fn display_duration(duration: Option<&Duration>) -> String {
+    match duration {
+        Some(duration) => duration.to_string(),
+        None => "none".to_string(),
+    }
+}
+
 /// Outputs the difference between two [ContractInfo]s as an [EventAdditionalMetadata]
 /// that can be appended to a [Response](cosmwasm_std::Response).
 ///
@@ -284,15 +419,105 @@ pub fn get_contract_state_update(
         changes.add_metadata("old_contract_version", old.contract_version.to_string());
         changes.add_metadata("new_contract_version", new.contract_version.to_string());
     }
-    if old.create_request_nhash_fee != new.create_request_nhash_fee {
-        changes.add_metadata(
-            "old_create_request_nhash_fee",
-            old.create_request_nhash_fee.to_string(),
-        );
-        changes.add_metadata(
-            "new_create_request_nhash_fee",
-            new.create_request_nhash_fee.to_string(),
-        );
+    if old.fee_schedule != new.fee_schedule {
+        changes.add_metadata("old_fee_schedule", display_fee_schedule(&old.fee_schedule));
+        changes.add_metadata("new_fee_schedule", display_fee_schedule(&new.fee_schedule));
     }
     changes
 }
+
+/// Applies a [SettingsUpdate] to the contract's information, returning the updated contract information
+/// without storing it.
+///
+/// # Parameters
+///
+/// * `api` The cosmwasm api, used to validate a new admin address.
+/// * `contract_info` The contract's current information.
+/// * `update` The changes to apply to the contract's settings.
+pub fn apply_settings_update(
+    api: &dyn Api,
+    contract_info: &ContractInfo,
+    update: &SettingsUpdate,
+) -> ContractResult<ContractInfo> {
+    let mut errors = vec![];
+    let mut new_contract_info = contract_info.clone();
+    if let Some(new_admin_address) = &update.new_admin_address {
+        match api.addr_validate(new_admin_address) {
+            Ok(new_admin) => new_contract_info.admin = new_admin,
+            Err(_) => errors.push(format!(
+                "new_admin_address [{}] was invalid",
+                new_admin_address
+            )),
+        }
+    }
+    if let Some(new_fee_schedule) = &update.new_fee_schedule {
+        errors.append(&mut validate_fee_schedule(api, new_fee_schedule));
+        new_contract_info.fee_schedule = new_fee_schedule.to_owned();
+    }
+    if let Some(new_contract_name) = &update.new_contract_name {
+        if new_contract_name.trim().is_empty() {
+            errors.push("new_contract_name value was empty".to_string());
+        }
+        new_contract_info.contract_name = new_contract_name.to_string();
+    }
+    if let Some(new_bind_name) = &update.new_bind_name {
+        if new_bind_name.trim().is_empty() {
+            errors.push("new_bind_name value was empty".to_string());
+        }
+        new_contract_info.bind_name = new_bind_name.to_string();
+    }
+    if !errors.is_empty() {
+        ContractError::InvalidRequest {
+            message: errors.join(", "),
+        }
+        .to_err()
+    } else {
+        new_contract_info.to_ok()
+    }
+}
+
+/// Moves the contract's name binding from one name to another, rebinding the name of every bound
+/// [ValidationDefinition] beneath the new name and storing the definitions' new bound names.
+/// Returns the messages which unbind the old names and bind the new ones.
+///
+/// # Parameters
+///
+/// * `storage` A mutable reference to the contract's internal storage.
+/// * `contract_address` The address of the contract, to which the new names are bound.
+/// * `old_bind_name` The name currently bound to the contract.
+/// * `new_bind_name` The name to bind to the contract in its place.
+pub fn rebind_contract_names(
+    storage: &mut dyn Storage,
+    contract_address: &Addr,
+    old_bind_name: &str,
+    new_bind_name: &str,
+) -> ContractResult<Vec<CosmosMsg<ProvenanceMsg>>> {
+    // Names beneath the old name are unbound before it, and those beneath the new name are bound after it
+    let mut messages = vec![];
+    let mut definition_bind_messages = vec![];
+    for old_definition in get_bound_validation_definitions(storage) {
+        if let Some(old_name) = old_definition.maybe_get_bound_name() {
+            messages.push(unbind_name(old_name)?);
+        }
+        let new_name = generate_validation_definition_attribute_name(
+            &old_definition.validation_type,
+            new_bind_name,
+        );
+        definition_bind_messages.push(bind_name(
+            &new_name,
+            contract_address.clone(),
+            NameBinding::Restricted,
+        )?);
+        let mut new_definition = old_definition.clone();
+        new_definition.bound_name = Some(new_name);
+        store_validation_definition(storage, &new_definition, Some(&old_definition))?;
+    }
+    messages.push(unbind_name(old_bind_name)?);
+    messages.push(bind_name(
+        new_bind_name,
+        contract_address.clone(),
+        NameBinding::Restricted,
+    )?);
+    messages.append(&mut definition_bind_messages);
+    messages.to_ok()
+}
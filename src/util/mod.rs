@@ -1,7 +1,10 @@
 pub mod aliases;
 pub mod constants;
 pub mod create_request_utilities;
+pub mod escrow;
 pub mod event_attributes;
 pub mod fees;
 pub mod functions;
 pub mod helpers;
+pub mod pagination;
+pub mod storage_overlay;
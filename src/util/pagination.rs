@@ -0,0 +1,74 @@
+use super::constants::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+use crate::types::page::Page;
+
+/// Determines how many items should be returned in a page, falling back to
+/// [DEFAULT_PAGE_LIMIT] if no limit is given and never exceeding [MAX_PAGE_LIMIT].
+///
+/// # Parameters
+///
+/// * `limit` The number of items requested, if any.
+pub fn get_page_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize
+}
+
+/// Collects the first page of items from an iterator over records in ascending order of their
+/// storage keys, which should already begin after the requested cursor.
+///
+/// # Parameters
+///
+/// * `records` An iterator over pairs of storage keys and the items stored under them.
+/// * `limit` The number of items requested, if any, which is capped by [get_page_limit].
+pub fn form_page<T>(records: impl Iterator<Item = (String, T)>, limit: Option<u32>) -> Page<T> {
+    let limit = get_page_limit(limit);
+    // Read one record beyond the limit to learn whether another page follows this one
+    let mut records = records.take(limit + 1).collect::<Vec<_>>();
+    let next_start_after = if records.len() > limit {
+        records.truncate(limit);
+        records.last().map(|(key, _)| key.clone())
+    } else {
+        None
+    };
+    Page {
+        items: records.into_iter().map(|(_, item)| item).collect(),
+        next_start_after,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{form_page, get_page_limit};
+    use crate::util::constants::{DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+
+    use proptest::{prop_assert, prop_assert_eq, proptest};
+
+    #[test]
+    fn page_limit_is_defaulted_and_capped() {
+        assert_eq!(DEFAULT_PAGE_LIMIT as usize, get_page_limit(None));
+        assert_eq!(1, get_page_limit(Some(1)));
+        assert_eq!(MAX_PAGE_LIMIT as usize, get_page_limit(Some(u32::MAX)));
+    }
+
+    proptest! {
+        #[test]
+        fn pages_cover_every_record_once(count in 0..100usize, limit in 1..40u32) {
+            let keys = (0..count).map(|i| format!("{:03}", i)).collect::<Vec<_>>();
+            let mut seen = vec![];
+            let mut start_after: Option<String> = None;
+            loop {
+                let page = form_page(
+                    keys.iter()
+                        .filter(|key| start_after.as_ref().is_none_or(|cursor| *key > cursor))
+                        .map(|key| (key.clone(), key.clone())),
+                    Some(limit),
+                );
+                prop_assert!(page.items.len() <= get_page_limit(Some(limit)));
+                seen.extend(page.items.clone());
+                match page.next_start_after {
+                    Some(cursor) => start_after = Some(cursor),
+                    None => break,
+                }
+            }
+            prop_assert_eq!(keys, seen);
+        }
+    }
+}
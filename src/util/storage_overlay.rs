@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Order, Record, Storage};
+
+/// A [Storage] which holds writes in memory on top of an immutable base storage, so that operations
+/// which modify storage, like a [migration](crate::migrate), can be simulated without persisting them.
+pub struct StorageOverlay<'a> {
+    /// The underlying storage, which is never modified.
+    base: &'a dyn Storage,
+    /// The values written over the base storage, where a value of [None] denotes a removed key.
+    writes: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+impl<'a> StorageOverlay<'a> {
+    pub fn new(base: &'a dyn Storage) -> Self {
+        Self {
+            base,
+            writes: BTreeMap::new(),
+        }
+    }
+    /// Returns the number of keys whose values differ from those in the base storage, excluding removed keys.
+    pub fn get_changed_key_count(&self) -> u32 {
+        self.writes
+            .iter()
+            .filter(|(key, value)| {
+                value
+                    .as_ref()
+                    .is_some_and(|value| self.base.get(key).as_ref() != Some(value))
+            })
+            .count() as u32
+    }
+    /// Returns the number of keys present in the base storage which have been removed.
+    pub fn get_removed_key_count(&self) -> u32 {
+        self.writes
+            .iter()
+            .filter(|(key, value)| value.is_none() && self.base.get(key).is_some())
+            .count() as u32
+    }
+}
+impl<'a> Storage for StorageOverlay<'a> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.writes.get(key) {
+            Some(value) => value.clone(),
+            None => self.base.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        let in_range =
+            |key: &[u8]| start.is_none_or(|start| key >= start) && end.is_none_or(|end| key < end);
+        let mut records = self
+            .base
+            .range(start, end, Order::Ascending)
+            .collect::<BTreeMap<_, _>>();
+        for (key, value) in self.writes.iter().filter(|(key, _)| in_range(key)) {
+            match value {
+                Some(value) => records.insert(key.clone(), value.clone()),
+                None => records.remove(key),
+            };
+        }
+        match order {
+            Order::Ascending => Box::new(records.into_iter()),
+            Order::Descending => Box::new(records.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.writes.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.writes.insert(key.to_vec(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StorageOverlay;
+
+    use cosmwasm_std::{testing::MockStorage, Order, Storage};
+
+    #[test]
+    fn overlay_reads_writes_without_modifying_base() {
+        let mut base = MockStorage::new();
+        base.set(b"a", b"1");
+        base.set(b"b", b"2");
+        base.set(b"c", b"3");
+        let mut overlay = StorageOverlay::new(&base);
+        overlay.set(b"b", b"4");
+        overlay.set(b"d", b"5");
+        overlay.remove(b"c");
+        overlay.set(b"a", b"1");
+
+        assert_eq!(Some(b"4".to_vec()), overlay.get(b"b"));
+        assert_eq!(None, overlay.get(b"c"));
+        assert_eq!(
+            vec![
+                (b"d".to_vec(), b"5".to_vec()),
+                (b"b".to_vec(), b"4".to_vec()),
+            ],
+            overlay
+                .range(Some(b"b"), None, Order::Descending)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(2, overlay.get_changed_key_count());
+        assert_eq!(1, overlay.get_removed_key_count());
+        assert_eq!(Some(b"3".to_vec()), base.get(b"c"));
+    }
+}